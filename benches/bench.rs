@@ -26,6 +26,8 @@
 /// so on.
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use micromap::Map;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 
 pub fn insert_benchmark(c: &mut Criterion) {
     c.bench_function("insert_same", |b| {
@@ -170,10 +172,64 @@ pub fn insert_exist_kv_in_diff_slot(c: &mut Criterion) {
     });
 }
 
+/// Generate `count` keys in `0..n` following a Zipf distribution (exponent 1.0),
+/// where key `0` is the most popular one.
+fn zipf_keys(n: usize, count: usize) -> Vec<u64> {
+    let weights: Vec<f64> = (1..=n).map(|rank| 1.0 / rank as f64).collect();
+    let total: f64 = weights.iter().sum();
+    let mut rng = SmallRng::seed_from_u64(0x5EED);
+    (0..count)
+        .map(|_| {
+            let mut x = rng.random::<f64>() * total;
+            let mut key = n - 1;
+            for (i, w) in weights.iter().enumerate() {
+                if x < *w {
+                    key = i;
+                    break;
+                }
+                x -= w;
+            }
+            key as u64
+        })
+        .collect()
+}
+
+pub fn promote_benchmark(c: &mut Criterion) {
+    const CAP: usize = 64;
+    let keys = zipf_keys(CAP, 1000);
+    // The most popular keys are inserted last, so they start at the tail of the array.
+    let make_map = || Map::<u64, u64, CAP>::from_iter((0..CAP as u64).rev().map(|k| (k, k)));
+    c.bench_function("zipf_get", |b| {
+        let m = make_map();
+        b.iter(|| {
+            for k in &keys {
+                black_box(m.get(black_box(k)));
+            }
+        });
+    });
+    c.bench_function("zipf_get_promote", |b| {
+        let mut m = make_map();
+        b.iter(|| {
+            for k in &keys {
+                black_box(m.get_promote(black_box(k)));
+            }
+        });
+    });
+    c.bench_function("zipf_get_promote_to_front", |b| {
+        let mut m = make_map();
+        b.iter(|| {
+            for k in &keys {
+                black_box(m.get_promote_to_front(black_box(k)));
+            }
+        });
+    });
+}
+
 criterion_group!(
     benches,
     insert_benchmark,
     length_benchmark,
+    promote_benchmark,
     // insert_exist_kv_in_diff_slot // ignored for now
 );
 criterion_main!(benches);
@@ -164,4 +164,17 @@ fn main() {
         assert_eq!(cache.get(&3), Some(&3)); // Some(&3)
         assert_eq!(cache.get(&4), Some(&4)); // Some(&4)
     }
+    {
+        // micromap::LruMap (built-in, no heap and only `K: PartialEq` needed)
+        let mut cache: micromap::LruMap<_, _, MAX> = micromap::LruMap::new();
+        cache.put(1, 1);
+        cache.put(2, 2);
+        assert_eq!(cache.get(&1), Some(&1)); // Some(&1)
+        assert_eq!(cache.put(3, 3), Some((2, 2))); // (2, 2) evicted
+        assert_eq!(cache.get(&2), None); // None (removed)
+        assert_eq!(cache.put(4, 4), Some((1, 1))); // (1, 1) evicted
+        assert_eq!(cache.get(&1), None); // None (removed)
+        assert_eq!(cache.get(&3), Some(&3)); // Some(&3)
+        assert_eq!(cache.get(&4), Some(&4)); // Some(&4)
+    }
 }
@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! A small bidirectional Map based on a fixed length array of left-right pairs.
+
+mod methods;
+
+use crate::map::Map;
+use core::fmt;
+
+/// A fixed-capacity bidirectional map.
+///
+/// Every left value maps to exactly one right value and vice versa. All the
+/// pairs are kept in one array, so it is not possible for the two directions
+/// to get out of sync, and a lookup in either direction is a linear scan.
+///
+/// ```
+/// use micromap::BiMap;
+/// let mut m: BiMap<u32, &str, 4> = BiMap::new();
+/// m.insert(1, "one");
+/// m.insert(2, "two");
+/// assert_eq!(m.get_by_left(&1), Some(&"one"));
+/// assert_eq!(m.get_by_right(&"two"), Some(&2));
+/// ```
+pub struct BiMap<L, R, const N: usize> {
+    map: Map<L, R, N>,
+}
+
+/// The pairs which were overwritten by [`BiMap::insert`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overwritten<L, R> {
+    /// Neither the left nor the right value existed in the map.
+    Neither,
+    /// The left value existed, and this pair was removed.
+    Left(L, R),
+    /// The right value existed, and this pair was removed.
+    Right(L, R),
+    /// The very same pair existed, and it was removed.
+    Pair(L, R),
+    /// Both values existed in two different pairs, and both pairs were
+    /// removed. The first one was found by the left value, the second one
+    /// was found by the right value.
+    Both((L, R), (L, R)),
+}
+
+impl<L, R> Overwritten<L, R> {
+    /// Returns `true` if at least one pair was overwritten.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let mut m: BiMap<u32, char, 2> = BiMap::new();
+    /// assert!(!m.insert(1, 'a').did_overwrite());
+    /// assert!(m.insert(1, 'b').did_overwrite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn did_overwrite(&self) -> bool {
+        !matches!(self, Self::Neither)
+    }
+}
+
+impl<L, R, const N: usize> Default for BiMap<L, R, N> {
+    /// Creates an empty [`BiMap`] like [`new()`][`BiMap::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: Clone, R: Clone, const N: usize> Clone for BiMap<L, R, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<L: fmt::Debug, R: fmt::Debug, const N: usize> fmt::Debug for BiMap<L, R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, L, R, const N: usize> IntoIterator for &'a BiMap<L, R, N> {
+    type Item = (&'a L, &'a R);
+    type IntoIter = crate::map::Iter<'a, L, R>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BiMap, Overwritten};
+
+    #[test]
+    fn various() {
+        let mut m: BiMap<u8, char, 3> = BiMap::default();
+        assert!(m.is_empty());
+        assert_eq!(m.capacity(), 3);
+        assert_eq!(m.insert(1, 'a'), Overwritten::Neither);
+        assert_eq!(m.insert(2, 'b'), Overwritten::Neither);
+        assert_eq!(m.insert(1, 'c'), Overwritten::Left(1, 'a'));
+        assert_eq!(m.insert(3, 'b'), Overwritten::Right(2, 'b'));
+        assert_eq!(m.len(), 2);
+        assert_eq!(format!("{m:?}"), "{1: 'c', 3: 'b'}");
+        let cloned = m.clone();
+        assert_eq!((&cloned).into_iter().count(), 2);
+        m.clear();
+        assert!(m.is_empty());
+        assert_eq!(cloned.get_by_right(&'c'), Some(&1));
+    }
+}
@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::{BiMap, Overwritten};
+use crate::map::{Iter, Keys, Map, Values};
+use crate::Equivalent;
+use core::mem;
+
+impl<L, R, const N: usize> BiMap<L, R, N> {
+    /// Creates an empty [`BiMap`] with capacity `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let m: BiMap<u32, &str, 8> = BiMap::new();
+    /// assert_eq!(m.capacity(), 8);
+    /// assert_eq!(m.len(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: Map::new() }
+    }
+
+    /// Returns the number of pairs the map can hold, which always equal to `N`.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of left-right pairs in the map.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no pair.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Clears the map, removing all pairs (drop them).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// An iterator visiting all left-right pairs in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, L, R> {
+        self.map.iter()
+    }
+
+    /// An iterator visiting all left values in arbitrary order.
+    #[inline]
+    pub fn left_values(&self) -> Keys<'_, L, R> {
+        self.map.keys()
+    }
+
+    /// An iterator visiting all right values in arbitrary order.
+    #[inline]
+    pub fn right_values(&self) -> Values<'_, L, R> {
+        self.map.values()
+    }
+}
+
+impl<L: PartialEq, R: PartialEq, const N: usize> BiMap<L, R, N> {
+    /// Returns a reference to the right value corresponding to the left one.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let mut m: BiMap<_, _, 3> = BiMap::new();
+    /// m.insert(1, "a");
+    /// assert_eq!(m.get_by_left(&1), Some(&"a"));
+    /// assert_eq!(m.get_by_left(&2), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_by_left<Q>(&self, l: &Q) -> Option<&R>
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        self.map.get(l)
+    }
+
+    /// Returns a reference to the left value corresponding to the right one.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let mut m: BiMap<_, _, 3> = BiMap::new();
+    /// m.insert(1, "a");
+    /// assert_eq!(m.get_by_right("a"), Some(&1));
+    /// assert_eq!(m.get_by_right("b"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_by_right<Q>(&self, r: &Q) -> Option<&L>
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        self.map.iter().find(|(_, x)| r.equivalent(*x)).map(|p| p.0)
+    }
+
+    /// Returns `true` if the map contains the left value.
+    #[inline]
+    #[must_use]
+    pub fn contains_left<Q>(&self, l: &Q) -> bool
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        self.map.contains_key(l)
+    }
+
+    /// Returns `true` if the map contains the right value.
+    #[inline]
+    #[must_use]
+    pub fn contains_right<Q>(&self, r: &Q) -> bool
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        self.map.values().any(|x| r.equivalent(x))
+    }
+
+    /// Removes the pair with the given left value, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let mut m: BiMap<_, _, 3> = BiMap::new();
+    /// m.insert(1, "a");
+    /// assert_eq!(m.remove_by_left(&1), Some((1, "a")));
+    /// assert_eq!(m.remove_by_left(&1), None);
+    /// ```
+    #[inline]
+    pub fn remove_by_left<Q>(&mut self, l: &Q) -> Option<(L, R)>
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        self.map.remove_entry(l)
+    }
+
+    /// Removes the pair with the given right value, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let mut m: BiMap<_, _, 3> = BiMap::new();
+    /// m.insert(1, "a");
+    /// assert_eq!(m.remove_by_right("a"), Some((1, "a")));
+    /// assert_eq!(m.remove_by_right("a"), None);
+    /// ```
+    #[inline]
+    pub fn remove_by_right<Q>(&mut self, r: &Q) -> Option<(L, R)>
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        let i = self.position_of_right(r)?;
+        Some(unsafe { self.map.remove_index_read(i) })
+    }
+
+    /// Inserts a left-right pair into the map, removing the existing pairs
+    /// which contain either value, and reports what was overwritten.
+    ///
+    /// # Panics
+    /// It may panic if neither value exists and there are too many pairs in the
+    /// map already. If you want to avoid this, use
+    /// [`insert_no_overwrite()`][Self::insert_no_overwrite] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// use micromap::bimap::Overwritten;
+    /// let mut m: BiMap<_, _, 3> = BiMap::new();
+    /// assert_eq!(m.insert(1, 'a'), Overwritten::Neither);
+    /// assert_eq!(m.insert(2, 'b'), Overwritten::Neither);
+    /// assert_eq!(m.insert(1, 'b'), Overwritten::Both((1, 'a'), (2, 'b')));
+    /// assert_eq!(m.len(), 1);
+    /// assert_eq!(m.insert(1, 'b'), Overwritten::Pair(1, 'b'));
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, l: L, r: R) -> Overwritten<L, R> {
+        let li = self.map.position_of(&l);
+        let ri = self.position_of_right(&r);
+        match (li, ri) {
+            (None, None) => {
+                self.map.push_back(l, r);
+                Overwritten::Neither
+            }
+            (Some(i), None) => {
+                let (l, r) = mem::replace(unsafe { self.map.item_mut(i) }, (l, r));
+                Overwritten::Left(l, r)
+            }
+            (None, Some(j)) => {
+                let (l, r) = mem::replace(unsafe { self.map.item_mut(j) }, (l, r));
+                Overwritten::Right(l, r)
+            }
+            (Some(i), Some(j)) if i == j => {
+                let (l, r) = mem::replace(unsafe { self.map.item_mut(i) }, (l, r));
+                Overwritten::Pair(l, r)
+            }
+            (Some(i), Some(j)) => {
+                let by_left = mem::replace(unsafe { self.map.item_mut(i) }, (l, r));
+                let by_right = unsafe { self.map.remove_index_read(j) };
+                Overwritten::Both(by_left, by_right)
+            }
+        }
+    }
+
+    /// Inserts a left-right pair into the map only if neither value exists
+    /// and there is room for it.
+    ///
+    /// # Errors
+    /// If either value exists already or the map is full, nothing is changed
+    /// and the given pair is returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::BiMap;
+    /// let mut m: BiMap<_, _, 2> = BiMap::new();
+    /// assert_eq!(m.insert_no_overwrite(1, 'a'), Ok(()));
+    /// assert_eq!(m.insert_no_overwrite(1, 'b'), Err((1, 'b')));
+    /// assert_eq!(m.insert_no_overwrite(2, 'a'), Err((2, 'a')));
+    /// assert_eq!(m.insert_no_overwrite(2, 'b'), Ok(()));
+    /// assert_eq!(m.insert_no_overwrite(3, 'c'), Err((3, 'c'))); // full
+    /// ```
+    #[inline]
+    pub fn insert_no_overwrite(&mut self, l: L, r: R) -> Result<(), (L, R)> {
+        if self.len() == N || self.contains_left(&l) || self.contains_right(&r) {
+            return Err((l, r));
+        }
+        self.map.push_back(l, r);
+        Ok(())
+    }
+
+    /// Internal function to find the index of the pair with the given right value.
+    #[inline]
+    fn position_of_right<Q>(&self, r: &Q) -> Option<usize>
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        self.map.values().position(|x| r.equivalent(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BiMap, Overwritten};
+
+    #[test]
+    fn lookups_both_directions() {
+        let mut m: BiMap<String, u16, 4> = BiMap::new();
+        m.insert("http".to_string(), 80);
+        m.insert("https".to_string(), 443);
+        assert_eq!(m.get_by_left("http"), Some(&80));
+        assert_eq!(m.get_by_right(&443), Some(&"https".to_string()));
+        assert!(m.contains_left("https"));
+        assert!(m.contains_right(&80));
+        assert!(!m.contains_right(&22));
+        assert_eq!(m.left_values().count(), 2);
+        assert_eq!(m.right_values().copied().sum::<u16>(), 523);
+    }
+
+    #[test]
+    fn insert_reports_overwritten() {
+        let mut m: BiMap<i32, char, 4> = BiMap::new();
+        assert_eq!(m.insert(1, 'a'), Overwritten::Neither);
+        assert_eq!(m.insert(2, 'b'), Overwritten::Neither);
+        assert_eq!(m.insert(3, 'c'), Overwritten::Neither);
+        assert_eq!(m.insert(1, 'a'), Overwritten::Pair(1, 'a'));
+        assert_eq!(m.insert(1, 'z'), Overwritten::Left(1, 'a'));
+        assert_eq!(m.insert(9, 'z'), Overwritten::Right(1, 'z'));
+        assert_eq!(m.insert(2, 'c'), Overwritten::Both((2, 'b'), (3, 'c')));
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get_by_left(&2), Some(&'c'));
+        assert_eq!(m.get_by_right(&'c'), Some(&2));
+        assert_eq!(m.get_by_left(&9), Some(&'z'));
+        assert_eq!(m.get_by_left(&3), None);
+        assert_eq!(m.get_by_right(&'b'), None);
+    }
+
+    #[test]
+    fn insert_both_when_left_is_last() {
+        let mut m: BiMap<i32, char, 3> = BiMap::new();
+        m.insert(1, 'a');
+        m.insert(2, 'b');
+        m.insert(3, 'c');
+        assert_eq!(m.insert(3, 'a'), Overwritten::Both((3, 'c'), (1, 'a')));
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get_by_left(&3), Some(&'a'));
+        assert_eq!(m.get_by_right(&'b'), Some(&2));
+    }
+
+    #[test]
+    fn full_map_can_overwrite() {
+        let mut m: BiMap<i32, i32, 1> = BiMap::new();
+        assert_eq!(m.insert(1, 1), Overwritten::Neither);
+        assert!(m.insert(1, 2).did_overwrite());
+        assert!(m.insert(2, 2).did_overwrite());
+        assert_eq!(m.get_by_right(&2), Some(&2));
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn insert_into_full_map_panics() {
+        let mut m: BiMap<i32, i32, 1> = BiMap::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+    }
+
+    #[test]
+    fn remove_by_both_sides() {
+        let mut m: BiMap<i32, &str, 3> = BiMap::new();
+        m.insert(1, "a");
+        m.insert(2, "b");
+        m.insert(3, "c");
+        assert_eq!(m.remove_by_right("a"), Some((1, "a")));
+        assert_eq!(m.remove_by_left(&3), Some((3, "c")));
+        assert_eq!(m.remove_by_right("c"), None);
+        assert_eq!(m.iter().collect::<Vec<_>>(), [(&2, &"b")]);
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! A small multiset counting equal items, based on a fixed length array.
+
+mod from;
+mod methods;
+mod ops;
+
+use crate::map::Map;
+use core::fmt;
+
+/// A fixed-capacity counter of up to `N` distinct items, like the `Counter`
+/// of Python.
+///
+/// Only positive counts are kept: an item disappears from the counter as soon
+/// as its count drops to zero, so [`len()`][Counter::len] is always the number
+/// of distinct items being counted.
+///
+/// ```
+/// use micromap::Counter;
+/// let c: Counter<char, 8> = "banana".chars().collect();
+/// assert_eq!(c.count(&'a'), 3);
+/// assert_eq!(c.count(&'z'), 0);
+/// assert_eq!(c.total(), 6);
+/// assert_eq!(c.most_common::<2>(), [Some((&'a', 3)), Some((&'n', 2))]);
+/// ```
+pub struct Counter<T, const N: usize> {
+    map: Map<T, usize, N>,
+}
+
+impl<T, const N: usize> Default for Counter<T, N> {
+    /// Creates an empty [`Counter`] like [`new()`][`Counter::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for Counter<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Counter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<Counter<T, M>> for Counter<T, N> {
+    /// Two counters are equal if they count the same items the same number of
+    /// times. (The capacity does not affect comparison.)
+    #[inline]
+    fn eq(&self, other: &Counter<T, M>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Counter<T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Counter<T, N> {
+    type Item = (&'a T, &'a usize);
+    type IntoIter = crate::map::Iter<'a, T, usize>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn various() {
+        let mut c: Counter<char, 3> = Counter::default();
+        assert!(c.is_empty());
+        assert_eq!(c.capacity(), 3);
+        c.add('a');
+        c.add('a');
+        c.add('b');
+        assert_eq!(c.len(), 2);
+        assert_eq!(format!("{c:?}"), "{'a': 2, 'b': 1}");
+        let cloned = c.clone();
+        assert_eq!(cloned, c);
+        assert_eq!((&cloned).into_iter().count(), 2);
+        c.clear();
+        assert!(c.is_empty());
+        assert_ne!(cloned, c);
+    }
+}
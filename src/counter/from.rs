@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Counter;
+
+impl<T: PartialEq, const N: usize> Counter<T, N> {
+    /// Counts the items of an iterator, without panic.
+    ///
+    /// # Errors
+    /// If there are more than `N` distinct items, the first item which does
+    /// not fit is returned in [`Err`], and the rest of the iterator is not
+    /// consumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let c = Counter::<_, 2>::try_from_iter("aab".chars()).unwrap();
+    /// assert_eq!(c.count(&'a'), 2);
+    /// assert_eq!(Counter::<_, 2>::try_from_iter("abc".chars()), Err('c'));
+    /// ```
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, T> {
+        let mut c = Self::new();
+        for x in iter {
+            c.try_add_by(x, 1)?;
+        }
+        Ok(c)
+    }
+}
+
+impl<T: PartialEq, const N: usize> FromIterator<T> for Counter<T, N> {
+    /// Counts the items of an iterator.
+    ///
+    /// # Panics
+    /// If there are more than `N` distinct items. If you want to avoid this,
+    /// use [`Counter::try_from_iter`] instead.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut c = Self::new();
+        for x in iter {
+            c.add(x);
+        }
+        c
+    }
+}
+
+impl<T: PartialEq, const N: usize> Extend<T> for Counter<T, N> {
+    /// Counts the items of an iterator in addition to the current counts.
+    ///
+    /// # Panics
+    /// If the number of distinct items exceeds `N`.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.add(x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut c: Counter<u8, 3> = [1, 1, 2].into_iter().collect();
+        c.extend([2, 3]);
+        assert_eq!(c.count(&1), 2);
+        assert_eq!(c.count(&2), 2);
+        assert_eq!(c.count(&3), 1);
+    }
+
+    #[test]
+    fn try_from_iter_stops_at_overflow() {
+        let mut iter = [1, 2, 1, 3, 4].into_iter();
+        assert_eq!(Counter::<u8, 2>::try_from_iter(iter.by_ref()), Err(3));
+        assert_eq!(iter.next(), Some(4));
+        let c = Counter::<u8, 2>::try_from_iter([1, 2, 1]);
+        assert_eq!(c.map(|c| c.total()), Ok(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn from_larger_iter_panics() {
+        let _c: Counter<u8, 1> = [1, 2].into_iter().collect();
+    }
+}
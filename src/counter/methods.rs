@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Counter;
+use crate::map::{Iter, Map};
+use crate::Equivalent;
+
+impl<T, const N: usize> Counter<T, N> {
+    /// Creates an empty [`Counter`] with capacity `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let c: Counter<&str, 8> = Counter::new();
+    /// assert_eq!(c.capacity(), 8);
+    /// assert_eq!(c.total(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: Map::new() }
+    }
+
+    /// Returns the number of distinct items the counter can hold, which always
+    /// equal to `N`.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of distinct items in the counter.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the counter contains no item.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Clears the counter, removing all items (drop them).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// An iterator visiting all distinct items with their counts in arbitrary
+    /// order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, usize> {
+        self.map.iter()
+    }
+
+    /// Returns the sum of all counts.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let c: Counter<_, 4> = [1, 2, 2, 3, 3, 3].into_iter().collect();
+    /// assert_eq!(c.total(), 6);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.map.values().sum()
+    }
+
+    /// Returns up to `K` most common items with their counts, from the most
+    /// common to the least. If there are less than `K` distinct items, the
+    /// tail of the array is filled with [`None`]. Items with equal counts are
+    /// ordered arbitrarily.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let c: Counter<_, 4> = "hello".chars().collect();
+    /// let [first, _, _, _, fifth] = c.most_common::<5>();
+    /// assert_eq!(first, Some((&'l', 2)));
+    /// assert_eq!(fifth, None);
+    /// ```
+    ///
+    /// # Performance
+    /// It takes O(len * K) time and no heap allocation.
+    #[must_use]
+    pub fn most_common<const K: usize>(&self) -> [Option<(&T, usize)>; K] {
+        let mut top = [None; K];
+        for (item, &count) in self {
+            if let Some(p) = top
+                .iter()
+                .position(|x: &Option<(&T, usize)>| x.map_or(true, |(_, c)| c < count))
+            {
+                top[p..].rotate_right(1);
+                top[p] = Some((item, count));
+            }
+        }
+        top
+    }
+}
+
+impl<T: PartialEq, const N: usize> Counter<T, N> {
+    /// Returns the count of the item, which is zero if it is absent.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let c: Counter<_, 4> = ["a", "b", "a"].into_iter().collect();
+    /// assert_eq!(c.count("a"), 2);
+    /// assert_eq!(c.count("c"), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn count<Q>(&self, x: &Q) -> usize
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.map.get(x).copied().unwrap_or(0)
+    }
+
+    /// Adds one to the count of the item, returning the new count.
+    ///
+    /// # Panics
+    /// If the item is absent and the counter is full already. If you want to
+    /// avoid this, use [`try_add_by()`][Self::try_add_by] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let mut c: Counter<_, 2> = Counter::new();
+    /// assert_eq!(c.add('x'), 1);
+    /// assert_eq!(c.add('x'), 2);
+    /// ```
+    #[inline]
+    pub fn add(&mut self, x: T) -> usize {
+        self.add_by(x, 1)
+    }
+
+    /// Adds `n` to the count of the item, returning the new count. Adding zero
+    /// to an absent item does not insert it.
+    ///
+    /// # Panics
+    /// If the item is absent, `n` is not zero and the counter is full already.
+    /// If you want to avoid this, use [`try_add_by()`][Self::try_add_by] instead.
+    #[inline]
+    pub fn add_by(&mut self, x: T, n: usize) -> usize {
+        if let Some(i) = self.map.position_of(&x) {
+            let c = unsafe { self.map.value_mut(i) };
+            *c += n;
+            *c
+        } else {
+            if n > 0 {
+                self.map.push_back(x, n);
+            }
+            n
+        }
+    }
+
+    /// Attempts to add `n` to the count of the item, returning the new count.
+    ///
+    /// # Errors
+    /// If the item is absent, `n` is not zero and the counter is full already,
+    /// nothing is changed and the item is returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let mut c: Counter<_, 1> = Counter::new();
+    /// assert_eq!(c.try_add_by('a', 2), Ok(2));
+    /// assert_eq!(c.try_add_by('a', 1), Ok(3));
+    /// assert_eq!(c.try_add_by('b', 1), Err('b'));
+    /// ```
+    #[inline]
+    pub fn try_add_by(&mut self, x: T, n: usize) -> Result<usize, T> {
+        if n > 0 && self.map.len() == N && !self.map.contains_key(&x) {
+            return Err(x);
+        }
+        Ok(self.add_by(x, n))
+    }
+
+    /// Subtracts one from the count of the item, returning the new count. The
+    /// item is removed when its count reaches zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let mut c: Counter<_, 2> = ['x', 'x'].into_iter().collect();
+    /// assert_eq!(c.subtract(&'x'), 1);
+    /// assert_eq!(c.subtract(&'x'), 0);
+    /// assert_eq!(c.subtract(&'x'), 0);
+    /// assert!(c.is_empty());
+    /// ```
+    #[inline]
+    pub fn subtract<Q>(&mut self, x: &Q) -> usize
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.subtract_by(x, 1)
+    }
+
+    /// Subtracts `n` from the count of the item, saturating at zero, and
+    /// returns the new count. The item is removed when its count reaches zero.
+    #[inline]
+    pub fn subtract_by<Q>(&mut self, x: &Q, n: usize) -> usize
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        let Some(i) = self.map.position_of(x) else {
+            return 0;
+        };
+        let c = unsafe { self.map.value_mut(i) };
+        *c = c.saturating_sub(n);
+        let c = *c;
+        if c == 0 {
+            unsafe { self.map.remove_index_drop(i) };
+        }
+        c
+    }
+
+    /// Removes the item from the counter, returning its count.
+    #[inline]
+    pub fn remove<Q>(&mut self, x: &Q) -> usize
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.map.remove(x).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn adds_and_subtracts() {
+        let mut c: Counter<&str, 2> = Counter::new();
+        assert_eq!(c.add_by("a", 0), 0);
+        assert!(c.is_empty());
+        assert_eq!(c.add_by("a", 3), 3);
+        assert_eq!(c.add("b"), 1);
+        assert_eq!(c.try_add_by("c", 0), Ok(0));
+        assert_eq!(c.try_add_by("c", 1), Err("c"));
+        assert_eq!(c.subtract_by("a", 2), 1);
+        assert_eq!(c.subtract_by("a", 5), 0);
+        assert_eq!(c.len(), 1);
+        assert_eq!(c.subtract("z"), 0);
+        assert_eq!(c.remove("b"), 1);
+        assert_eq!(c.remove("b"), 0);
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn most_common_in_order() {
+        let c: Counter<u8, 5> = [3, 1, 2, 3, 2, 3].into_iter().collect();
+        assert_eq!(
+            c.most_common::<3>(),
+            [Some((&3, 3)), Some((&2, 2)), Some((&1, 1))]
+        );
+        assert_eq!(c.most_common::<0>(), []);
+        let empty: Counter<u8, 5> = Counter::new();
+        assert_eq!(empty.most_common::<2>(), [None, None]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn add_to_full_counter_panics() {
+        let mut c: Counter<u8, 1> = Counter::new();
+        c.add(1);
+        c.add(2);
+    }
+}
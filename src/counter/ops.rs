@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Counter;
+use core::ops::{Add, BitAnd, BitOr, Sub};
+
+impl<T, const M: usize, const N: usize> Add<&Counter<T, M>> for &Counter<T, N>
+where
+    T: PartialEq + Clone,
+{
+    type Output = Counter<T, N>;
+
+    /// Adds the counts of two counters.
+    ///
+    /// # Panics
+    /// If there are more than `N` distinct items in the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let a: Counter<_, 4> = "aab".chars().collect();
+    /// let b: Counter<_, 4> = "abc".chars().collect();
+    /// let c = &a + &b;
+    /// assert_eq!(c, "aaabbc".chars().collect::<Counter<_, 3>>());
+    /// ```
+    #[inline]
+    fn add(self, rhs: &Counter<T, M>) -> Counter<T, N> {
+        let mut c = self.clone();
+        for (x, n) in rhs {
+            c.add_by(x.clone(), *n);
+        }
+        c
+    }
+}
+
+impl<T, const M: usize, const N: usize> Sub<&Counter<T, M>> for &Counter<T, N>
+where
+    T: PartialEq + Clone,
+{
+    type Output = Counter<T, N>;
+
+    /// Subtracts the counts of `rhs`, keeping only the positive results.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let a: Counter<_, 4> = "aaab".chars().collect();
+    /// let b: Counter<_, 4> = "abbc".chars().collect();
+    /// let c = &a - &b;
+    /// assert_eq!(c, "aa".chars().collect::<Counter<_, 1>>());
+    /// ```
+    #[inline]
+    fn sub(self, rhs: &Counter<T, M>) -> Counter<T, N> {
+        let mut c = Counter::new();
+        for (x, n) in self {
+            c.add_by(x.clone(), n.saturating_sub(rhs.count(x)));
+        }
+        c
+    }
+}
+
+impl<T, const M: usize, const N: usize> BitOr<&Counter<T, M>> for &Counter<T, N>
+where
+    T: PartialEq + Clone,
+{
+    type Output = Counter<T, N>;
+
+    /// Returns the maximum of the counts of two counters.
+    ///
+    /// # Panics
+    /// If there are more than `N` distinct items in the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let a: Counter<_, 4> = "aaab".chars().collect();
+    /// let b: Counter<_, 4> = "abbc".chars().collect();
+    /// let c = &a | &b;
+    /// assert_eq!(c, "aaabbc".chars().collect::<Counter<_, 3>>());
+    /// ```
+    #[inline]
+    fn bitor(self, rhs: &Counter<T, M>) -> Counter<T, N> {
+        let mut c = self.clone();
+        for (x, &n) in rhs {
+            let m = c.count(x);
+            if n > m {
+                c.add_by(x.clone(), n - m);
+            }
+        }
+        c
+    }
+}
+
+impl<T, const M: usize, const N: usize> BitAnd<&Counter<T, M>> for &Counter<T, N>
+where
+    T: PartialEq + Clone,
+{
+    type Output = Counter<T, N>;
+
+    /// Returns the minimum of the counts of two counters, keeping only the
+    /// items present in both.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Counter;
+    /// let a: Counter<_, 4> = "aaab".chars().collect();
+    /// let b: Counter<_, 4> = "abbc".chars().collect();
+    /// let c = &a & &b;
+    /// assert_eq!(c, "ab".chars().collect::<Counter<_, 2>>());
+    /// ```
+    #[inline]
+    fn bitand(self, rhs: &Counter<T, M>) -> Counter<T, N> {
+        let mut c = Counter::new();
+        for (x, &n) in self {
+            c.add_by(x.clone(), n.min(rhs.count(x)));
+        }
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn python_semantics() {
+        let a: Counter<char, 4> = "aaabbc".chars().collect();
+        let b: Counter<char, 4> = "abbbd".chars().collect();
+        assert_eq!(&a + &b, "aaaabbbbbcd".chars().collect::<Counter<_, 4>>());
+        assert_eq!(&a - &b, "aac".chars().collect::<Counter<_, 2>>());
+        assert_eq!(&b - &a, "bd".chars().collect::<Counter<_, 2>>());
+        assert_eq!(&a | &b, "aaabbbcd".chars().collect::<Counter<_, 4>>());
+        assert_eq!(&a & &b, "abb".chars().collect::<Counter<_, 2>>());
+    }
+
+    #[test]
+    fn with_empty_counter() {
+        let a: Counter<u8, 2> = [1, 2, 2].into_iter().collect();
+        let e: Counter<u8, 3> = Counter::new();
+        assert_eq!(&a + &e, a);
+        assert_eq!(&a - &e, a);
+        assert_eq!(&a | &e, a);
+        assert!((&a & &e).is_empty());
+        assert!((&e - &a).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn add_overflow_panics() {
+        let a: Counter<char, 1> = "a".chars().collect();
+        let b: Counter<char, 1> = "b".chars().collect();
+        let _ = &a + &b;
+    }
+}
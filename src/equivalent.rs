@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! The trait for looking up keys with a query of another type.
+
+use core::borrow::Borrow;
+
+/// Key equivalence trait, which is used to look up keys by a query of another
+/// type, like the ones in `hashbrown` and `indexmap`.
+///
+/// It is implemented for every `Q` which any borrowed form `K` can be compared
+/// with, so it works like a `K: Borrow<Q>` bound out of the box. Implement it
+/// for your own query types, when the borrowed form of the key can not be
+/// expressed with [`Borrow`]. For example, a key `(String, u16)` can not be
+/// borrowed as `(&str, u16)`:
+///
+/// ```
+/// use micromap::{Equivalent, Map};
+/// struct Query<'a>(&'a str, u16);
+/// impl Equivalent<(String, u16)> for Query<'_> {
+///     fn equivalent(&self, key: &(String, u16)) -> bool {
+///         self.0 == key.0 && self.1 == key.1
+///     }
+/// }
+/// let mut m: Map<(String, u16), &str, 4> = Map::new();
+/// m.insert(("localhost".to_string(), 80), "http");
+/// assert_eq!(m.get(&Query("localhost", 80)), Some(&"http"));
+/// assert!(!m.contains_key(&Query("localhost", 443)));
+/// ```
+pub trait Equivalent<K: ?Sized> {
+    /// Checks if this value is equivalent to the given key.
+    ///
+    /// It must be consistent with the equality of the keys: two keys equal to
+    /// each other are equivalent to the same values.
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+impl<Q, K> Equivalent<K> for Q
+where
+    Q: PartialEq + ?Sized,
+    K: Borrow<Q> + ?Sized,
+{
+    #[inline]
+    fn equivalent(&self, key: &K) -> bool {
+        *self == *key.borrow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Equivalent;
+    use crate::{BiMap, Counter, LruMap, Map, MultiMap, Set, TtlMap};
+
+    struct Query<'a>(&'a str, u16);
+
+    impl Equivalent<(String, u16)> for Query<'_> {
+        fn equivalent(&self, key: &(String, u16)) -> bool {
+            self.0 == key.0 && self.1 == key.1
+        }
+    }
+
+    fn key(s: &str, p: u16) -> (String, u16) {
+        (s.to_string(), p)
+    }
+
+    #[test]
+    fn map_lookups_by_query() {
+        let mut m: Map<(String, u16), i32, 4> =
+            [(key("a", 1), 1), (key("b", 2), 2)].into_iter().collect();
+        assert_eq!(m.get_key_value(&Query("a", 1)), Some((&key("a", 1), &1)));
+        *m.get_mut(&Query("b", 2)).unwrap() += 10;
+        assert_eq!(m.get(&Query("b", 2)), Some(&12));
+        let [a, b] = m.get_disjoint_mut([&Query("a", 1), &Query("b", 2)]);
+        core::mem::swap(a.unwrap(), b.unwrap());
+        assert_eq!(m.remove(&Query("a", 1)), Some(12));
+        assert_eq!(m.remove_entry(&Query("b", 2)), Some((key("b", 2), 1)));
+        assert!(!m.contains_key(&Query("b", 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlapping keys")]
+    fn map_overlapping_queries() {
+        let mut m: Map<(String, u16), i32, 4> = Map::new();
+        m.insert(key("a", 1), 1);
+        let _ = m.get_disjoint_mut([&Query("a", 1), &Query("a", 1)]);
+    }
+
+    #[test]
+    fn set_lookups_by_query() {
+        let mut s: Set<(String, u16), 4> = [key("a", 1), key("b", 2)].into_iter().collect();
+        assert!(s.contains(&Query("a", 1)));
+        assert_eq!(s.get(&Query("b", 2)), Some(&key("b", 2)));
+        assert_eq!(s.take(&Query("b", 2)), Some(key("b", 2)));
+        assert!(s.remove(&Query("a", 1)));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn other_collections_lookups_by_query() {
+        let mut lru: LruMap<(String, u16), i32, 2> = LruMap::new();
+        lru.put(key("a", 1), 1);
+        assert_eq!(lru.get(&Query("a", 1)), Some(&1));
+        assert_eq!(lru.remove(&Query("a", 1)), Some(1));
+        let mut ttl: TtlMap<(String, u16), i32, u64, 2> = TtlMap::new();
+        ttl.insert(key("a", 1), 1, 10);
+        assert_eq!(ttl.get(&Query("a", 1), 0), Some(&1));
+        assert_eq!(ttl.deadline(&Query("a", 1)), Some(&10));
+        let mut bi: BiMap<(String, u16), (String, u16), 2> = BiMap::new();
+        bi.insert(key("a", 1), key("b", 2));
+        assert_eq!(bi.get_by_left(&Query("a", 1)), Some(&key("b", 2)));
+        assert!(bi.contains_right(&Query("b", 2)));
+        assert!(bi.remove_by_right(&Query("b", 2)).is_some());
+        let mut mm: MultiMap<(String, u16), i32, 4> = MultiMap::new();
+        mm.insert(key("a", 1), 1);
+        mm.insert(key("a", 1), 2);
+        assert_eq!(mm.get_all(&Query("a", 1)).count(), 2);
+        assert_eq!(mm.remove_all(&Query("a", 1)), 2);
+        let mut c: Counter<(String, u16), 2> = [key("a", 1), key("a", 1)].into_iter().collect();
+        assert_eq!(c.count(&Query("a", 1)), 2);
+        assert_eq!(c.remove(&Query("a", 1)), 2);
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! The strategies for comparing the keys of a [`Map`][crate::Map].
+
+use crate::Equivalent;
+use core::cmp::Ordering;
+
+/// Key equality strategy of a [`Map`][crate::Map].
+///
+/// Every lookup and insertion of the map compares the keys through the
+/// strategy, which is the last type argument `E` of the map. By default it is
+/// [`DefaultEq`], which uses [`PartialEq`] (via [`Equivalent`]), so the maps
+/// behave exactly as before. Other strategies may be picked at creation with
+/// [`Map::with_key_eq()`][crate::Map::with_key_eq]:
+///
+/// ```
+/// use micromap::{AsciiCaseInsensitive, Map};
+/// let mut headers: Map<&str, &str, 4, _> = Map::with_key_eq(AsciiCaseInsensitive);
+/// headers.insert("Content-Type", "text/html");
+/// assert_eq!(headers.get("content-type"), Some(&"text/html"));
+/// assert_eq!(headers.insert("CONTENT-TYPE", "text/plain"), Some("text/html"));
+/// assert_eq!(headers.len(), 1);
+/// ```
+///
+/// The second type argument `Q` is the type of the query, which is the key
+/// type itself for insertions. The strategy must behave like an equivalence
+/// relation on the keys, otherwise the map may hold "equal" keys twice.
+pub trait KeyEq<K: ?Sized, Q: ?Sized = K> {
+    /// Checks if the key of the map matches the query.
+    fn key_eq(&self, key: &K, query: &Q) -> bool;
+}
+
+/// The default strategy, which compares the keys with [`PartialEq`], and
+/// accepts any [`Equivalent`] query.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultEq;
+
+impl<K: ?Sized, Q: Equivalent<K> + ?Sized> KeyEq<K, Q> for DefaultEq {
+    #[inline]
+    fn key_eq(&self, key: &K, query: &Q) -> bool {
+        query.equivalent(key)
+    }
+}
+
+/// The strategy comparing string keys with ASCII case ignored, like
+/// [`str::eq_ignore_ascii_case`] does.
+///
+/// It accepts any key and query which may be viewed as [`str`], so a map with
+/// [`String`] keys may be looked up by a `&str`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AsciiCaseInsensitive;
+
+impl<K, Q> KeyEq<K, Q> for AsciiCaseInsensitive
+where
+    K: AsRef<str> + ?Sized,
+    Q: AsRef<str> + ?Sized,
+{
+    #[inline]
+    fn key_eq(&self, key: &K, query: &Q) -> bool {
+        key.as_ref().eq_ignore_ascii_case(query.as_ref())
+    }
+}
+
+/// The strategy comparing float keys by their total order, as defined by
+/// [`f64::total_cmp`].
+///
+/// Unlike [`PartialEq`], a NaN is equal to itself (if it has the same bits),
+/// so NaN keys may be found again, while `0.0` and `-0.0` are different keys.
+///
+/// ```
+/// use micromap::{Map, TotalOrd};
+/// let mut m: Map<f64, &str, 4, _> = Map::with_key_eq(TotalOrd);
+/// m.insert(f64::NAN, "nan");
+/// m.insert(0.0, "zero");
+/// assert_eq!(m.get(&f64::NAN), Some(&"nan"));
+/// assert_eq!(m.get(&-0.0), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TotalOrd;
+
+impl KeyEq<f32> for TotalOrd {
+    #[inline]
+    fn key_eq(&self, key: &f32, query: &f32) -> bool {
+        key.total_cmp(query) == Ordering::Equal
+    }
+}
+
+impl KeyEq<f64> for TotalOrd {
+    #[inline]
+    fn key_eq(&self, key: &f64, query: &f64) -> bool {
+        key.total_cmp(query) == Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsciiCaseInsensitive, DefaultEq, KeyEq, TotalOrd};
+    use crate::map::Entry;
+    use crate::Map;
+
+    #[test]
+    fn default_eq_uses_partial_eq() {
+        assert!(DefaultEq.key_eq(&String::from("a"), "a"));
+        assert!(!KeyEq::<f64>::key_eq(&DefaultEq, &f64::NAN, &f64::NAN));
+    }
+
+    #[test]
+    fn ignores_ascii_case() {
+        assert!(AsciiCaseInsensitive.key_eq(&String::from("Host"), "hOST"));
+        assert!(!AsciiCaseInsensitive.key_eq("Straße", "STRASSE"));
+    }
+
+    #[test]
+    fn compares_floats_by_total_order() {
+        assert!(TotalOrd.key_eq(&f64::NAN, &f64::NAN));
+        assert!(TotalOrd.key_eq(&f32::INFINITY, &f32::INFINITY));
+        assert!(!TotalOrd.key_eq(&0.0_f64, &-0.0));
+        assert!(!TotalOrd.key_eq(&f64::NAN, &-f64::NAN));
+    }
+
+    #[test]
+    fn case_insensitive_map() {
+        let mut m: Map<String, u32, 3, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        m.insert("Host".to_string(), 1);
+        assert_eq!(m.insert("HOST".to_string(), 2), Some(1));
+        assert_eq!(m.len(), 1);
+        m.entry("host".to_string()).and_modify(|v| *v += 1);
+        assert_eq!(m["hOsT"], 3);
+        assert!(matches!(m.entry("Accept".to_string()), Entry::Vacant(_)));
+        let cloned = m.clone();
+        assert_eq!(cloned.get("host"), Some(&3));
+        assert_eq!(m.remove_entry("host"), Some(("Host".to_string(), 3)));
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn collects_into_case_insensitive_map() {
+        let m: Map<&str, u8, 3, AsciiCaseInsensitive> =
+            [("a", 1), ("A", 2), ("b", 3)].into_iter().collect();
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get("a"), Some(&2));
+        let other: Map<&str, u8, 4, AsciiCaseInsensitive> =
+            [("B", 3), ("A", 2)].into_iter().collect();
+        assert_eq!(m, other);
+        assert_eq!(m.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn finds_nan_keys() {
+        let mut m: Map<f64, u8, 4, TotalOrd> = Map::default();
+        m.insert(f64::NAN, 1);
+        m.insert(f64::NAN, 2);
+        m.insert(0.0, 3);
+        m.insert(-0.0, 4);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m.get(&f64::NAN), Some(&2));
+        assert_eq!(m.remove(&-0.0), Some(4));
+        assert_eq!(m[&0.0], 3);
+    }
+
+    #[test]
+    fn custom_strategy() {
+        struct Modulo(u32);
+        impl KeyEq<u32> for Modulo {
+            fn key_eq(&self, key: &u32, query: &u32) -> bool {
+                key % self.0 == query % self.0
+            }
+        }
+        let mut m: Map<u32, char, 4, Modulo> = Map::with_key_eq(Modulo(10));
+        m.insert(1, 'a');
+        m.insert(11, 'b');
+        m.insert(2, 'c');
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.key_eq().0, 10);
+        assert_eq!(m.get(&21), Some(&'b'));
+        assert!(m.contains_key(&12));
+    }
+}
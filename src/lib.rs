@@ -49,6 +49,73 @@
 //! assert_eq!(set_union, Set::from([1, 2, 3, 4, 5]));
 //! assert_eq!(set_diff, Set::from([3]));
 //! ```
+//!
+//! ## [`LruMap`]
+//! A fixed-capacity LRU cache, which keeps its pairs in recency order inside
+//! the same kind of array, so it needs neither heap nor [`Hash`][core::hash::Hash].
+//! ```
+//! use micromap::LruMap;
+//! let mut cache: LruMap<u32, &str, 2> = LruMap::new();
+//! cache.put(1, "foo");
+//! cache.put(2, "bar");
+//! cache.get(&1);
+//! assert_eq!(cache.put(3, "baz"), Some((2, "bar"))); // evict the least recent
+//! ```
+//!
+//! ## [`TtlMap`]
+//! A map whose entries expire at a deadline. The clock is supplied by the caller
+//! as any [`Ord`] timestamp, so it works in `no_std` as well.
+//! ```
+//! use micromap::TtlMap;
+//! let mut m: TtlMap<u32, &str, u64, 4> = TtlMap::new();
+//! m.insert(1, "foo", 100); // expires at tick 100
+//! assert_eq!(m.get(&1, 99), Some(&"foo"));
+//! assert_eq!(m.get(&1, 100), None);
+//! assert_eq!(m.purge_expired(100).count(), 1);
+//! ```
+//!
+//! ## [`BiMap`]
+//! A bidirectional map, which keeps left-right pairs in one array, so it can be
+//! looked up from both sides without keeping two maps in sync.
+//! ```
+//! use micromap::BiMap;
+//! let mut m: BiMap<u16, &str, 4> = BiMap::new();
+//! m.insert(80, "http");
+//! assert_eq!(m.get_by_left(&80), Some(&"http"));
+//! assert_eq!(m.get_by_right(&"http"), Some(&80));
+//! ```
+//!
+//! ## [`MultiMap`]
+//! A map which can hold several values for the same key, in insertion order.
+//! ```
+//! use micromap::MultiMap;
+//! let mut m: MultiMap<&str, &str, 4> = MultiMap::new();
+//! m.insert("accept", "text/html");
+//! m.insert("accept", "image/png");
+//! assert_eq!(m.get_all("accept").count(), 2);
+//! assert_eq!(m.remove_all("accept"), 2);
+//! ```
+//!
+//! ## [`Counter`]
+//! A multiset counting equal items, with the arithmetic of Python's `Counter`.
+//! ```
+//! use micromap::Counter;
+//! let words: Counter<&str, 8> = "the cat in the hat".split(' ').collect();
+//! assert_eq!(words.count("cat"), 1);
+//! assert_eq!(words.most_common::<1>(), [Some((&"the", 2))]);
+//! ```
+//!
+//! ## Key equality
+//! A [`Map`] compares its keys with [`PartialEq`] by default, but another
+//! [`KeyEq`] strategy may be picked, like [`AsciiCaseInsensitive`] for header
+//! names or [`TotalOrd`] for float keys (even NaN).
+//! ```
+//! use micromap::{AsciiCaseInsensitive, Map};
+//! let mut m: Map<&str, u32, 4, _> = Map::with_key_eq(AsciiCaseInsensitive);
+//! m.insert("Content-Length", 42);
+//! assert_eq!(m["content-length"], 42);
+//! ```
+//!
 //! ## `no_std` support and Optional features
 //! The `micromap` does not depend on [`std`], so `no_std` is supported by default.
 //!
@@ -87,9 +154,23 @@
 #![warn(missing_docs)]
 #![doc(test(attr(deny(unused))))]
 
+pub mod bimap;
+pub mod counter;
+mod equivalent;
+mod key_eq;
+pub mod lru;
 pub mod map;
+pub mod multimap;
 pub mod set;
+pub mod ttl;
 
 // re-export Set
+pub use bimap::BiMap;
+pub use counter::Counter;
+pub use equivalent::Equivalent;
+pub use key_eq::{AsciiCaseInsensitive, DefaultEq, KeyEq, TotalOrd};
+pub use lru::LruMap;
 pub use map::Map;
+pub use multimap::MultiMap;
 pub use set::Set;
+pub use ttl::TtlMap;
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! A small LRU cache based on a [`Map`] which keeps its pairs in recency order.
+
+mod methods;
+
+use crate::map::Map;
+use core::fmt;
+
+/// A fixed-capacity LRU (least recently used) cache.
+///
+/// The pairs are kept in the fixed-size array of the inner [`Map`], ordered
+/// from the most recently used one to the least recently used one. So it needs
+/// no heap at all, and the keys only need to implement [`PartialEq`].
+///
+/// For example, this is how you make a cache, which is capable of storing
+/// up to two key-value pairs:
+///
+/// ```
+/// use micromap::LruMap;
+/// let mut cache: LruMap<u32, &str, 2> = LruMap::new();
+/// assert_eq!(cache.put(1, "one"), None);
+/// assert_eq!(cache.put(2, "two"), None);
+/// assert_eq!(cache.get(&1), Some(&"one")); // `1` is the most recent now
+/// assert_eq!(cache.put(3, "three"), Some((2, "two"))); // so `2` is evicted
+/// assert_eq!(cache.keys().collect::<Vec<_>>(), [&3, &1]);
+/// ```
+///
+/// Every access, which touches a pair, moves it to the front of the array, so
+/// it takes O(len) time, like the lookup itself.
+pub struct LruMap<K, V, const N: usize> {
+    map: Map<K, V, N>,
+}
+
+impl<K, V, const N: usize> Default for LruMap<K, V, N> {
+    /// Creates an empty [`LruMap`] like [`new()`][`LruMap::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for LruMap<K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for LruMap<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a LruMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = crate::map::Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LruMap;
+
+    #[test]
+    fn various() {
+        let mut cache: LruMap<i32, char, 3> = LruMap::default();
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 3);
+        assert_eq!(cache.put(1, 'a'), None);
+        assert_eq!(cache.put(2, 'b'), None);
+        assert_eq!(cache.put(3, 'c'), None);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(&1), Some(&'a'));
+        assert_eq!(cache.peek(&2), Some(&'b'));
+        assert_eq!(cache.put(4, 'd'), Some((2, 'b')));
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), [4, 1, 3]);
+        assert_eq!(cache.put(3, 'C'), None);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), [3, 4, 1]);
+        let cloned = cache.clone();
+        assert_eq!(format!("{cloned:?}"), "{3: 'C', 4: 'd', 1: 'a'}");
+        assert_eq!(cache.pop_lru(), Some((1, 'a')));
+        assert_eq!((&cache).into_iter().count(), 2);
+        cache.clear();
+        assert_eq!(cache.pop_lru(), None);
+    }
+}
@@ -0,0 +1,382 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::LruMap;
+use crate::map::{Iter, Keys, Map, Values};
+use crate::Equivalent;
+
+impl<K, V, const N: usize> LruMap<K, V, N> {
+    /// Creates an empty [`LruMap`] with capacity `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let cache: LruMap<&str, i32, 8> = LruMap::new();
+    /// assert_eq!(cache.capacity(), 8);
+    /// assert_eq!(cache.len(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: Map::new() }
+    }
+
+    /// Returns the number of key-value pairs the cache can hold,
+    /// which always equal to `N`.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of key-value pairs in the cache.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the cache contains no key-value pair.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Clears the cache, removing all key-value pairs (drop them).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Removes the least recently used pair from the cache and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 3> = LruMap::new();
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// assert_eq!(cache.pop_lru(), Some((1, "a")));
+    /// assert_eq!(cache.pop_lru(), Some((2, "b")));
+    /// assert_eq!(cache.pop_lru(), None);
+    /// ```
+    #[inline]
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        self.map.pop_back()
+    }
+
+    /// Returns the least recently used pair without touching it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 3> = LruMap::new();
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// assert_eq!(cache.peek_lru(), Some((&1, &"a")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.map.iter().last()
+    }
+
+    /// An iterator visiting all key-value pairs from the most recently used one
+    /// to the least recently used one. Iterating does not touch the pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 3> = LruMap::new();
+    /// cache.put("a", 1);
+    /// cache.put("b", 2);
+    /// cache.get("a");
+    /// assert_eq!(cache.iter().collect::<Vec<_>>(), [(&"a", &1), (&"b", &2)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    /// An iterator visiting all keys from the most recently used one to the
+    /// least recently used one.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.map.keys()
+    }
+
+    /// An iterator visiting all values from the most recently used one to the
+    /// least recently used one.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.map.values()
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> LruMap<K, V, N> {
+    /// Returns a reference to the value corresponding to the key, and marks
+    /// the pair as the most recently used one.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 2> = LruMap::new();
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// assert_eq!(cache.get(&1), Some(&"a"));
+    /// assert_eq!(cache.get(&3), None);
+    /// assert_eq!(cache.pop_lru(), Some((2, "b")));
+    /// ```
+    #[inline]
+    pub fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get_promote_to_front(k).map(|v| &*v)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key, and
+    /// marks the pair as the most recently used one.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 2> = LruMap::new();
+    /// cache.put(1, 10);
+    /// *cache.get_mut(&1).unwrap() += 1;
+    /// assert_eq!(cache.peek(&1), Some(&11));
+    /// ```
+    #[inline]
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get_promote_to_front(k)
+    }
+
+    /// Returns a reference to the value corresponding to the key, without
+    /// changing the recency order.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 2> = LruMap::new();
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// assert_eq!(cache.peek(&1), Some(&"a"));
+    /// assert_eq!(cache.pop_lru(), Some((1, "a")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get(k)
+    }
+
+    /// Returns `true` if the cache contains a value for the specified key,
+    /// without changing the recency order.
+    #[inline]
+    #[must_use]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.contains_key(k)
+    }
+
+    /// Puts a key-value pair into the cache as the most recently used one.
+    ///
+    /// Returns the evicted pair, if any:
+    /// - If the key exists already, the value is updated (the key is not),
+    ///   the old value is dropped, and [`None`] is returned, since nothing
+    ///   is evicted;
+    /// - If the key does not exist and the cache is full, the least recently
+    ///   used pair is evicted and returned;
+    /// - Otherwise, [`None`] is returned.
+    ///
+    /// For a cache with zero capacity, the given pair is evicted and returned
+    /// at once. Use [`insert()`][Self::insert] to get the old value back.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 2> = LruMap::new();
+    /// assert_eq!(cache.put(1, "a"), None);
+    /// assert_eq!(cache.put(2, "b"), None);
+    /// assert_eq!(cache.put(1, "A"), None); // updated, not evicted
+    /// assert_eq!(cache.put(3, "c"), Some((2, "b")));
+    /// assert_eq!(cache.peek(&1), Some(&"A"));
+    /// ```
+    #[inline]
+    pub fn put(&mut self, k: K, v: V) -> Option<(K, V)> {
+        if let Some(i) = self.map.position_of(&k) {
+            self.map.move_to_front(i);
+            *unsafe { self.map.value_mut(0) } = v;
+            return None;
+        }
+        self.push_evicting(k, v)
+    }
+
+    /// Puts a key-value pair into the cache as the most recently used one.
+    ///
+    /// If the key exists already, the value is updated (the key is not), and
+    /// the old value is returned. Otherwise, [`None`] is returned, and if the
+    /// cache is full, the least recently used pair is evicted and dropped.
+    /// Use [`put()`][Self::put] to get the evicted pair back.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 2> = LruMap::new();
+    /// assert_eq!(cache.insert(1, "a"), None);
+    /// assert_eq!(cache.insert(2, "b"), None);
+    /// assert_eq!(cache.insert(1, "A"), Some("a"));
+    /// assert_eq!(cache.insert(3, "c"), None); // (2, "b") is evicted
+    /// assert_eq!(cache.keys().collect::<Vec<_>>(), [&3, &1]);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(i) = self.map.position_of(&k) {
+            self.map.move_to_front(i);
+            return Some(core::mem::replace(unsafe { self.map.value_mut(0) }, v));
+        }
+        self.push_evicting(k, v);
+        None
+    }
+
+    /// Pushes a pair with a new key to the front, evicting the least recently
+    /// used pair if the cache is full.
+    #[inline]
+    fn push_evicting(&mut self, k: K, v: V) -> Option<(K, V)> {
+        if N == 0 {
+            return Some((k, v));
+        }
+        let evicted = if self.map.len() == N {
+            self.map.pop_back()
+        } else {
+            None
+        };
+        self.map.push_front(k, v);
+        evicted
+    }
+
+    /// Removes a key from the cache, returning the value at the key if the key
+    /// was previously in the cache. The recency order of the other pairs is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::LruMap;
+    /// let mut cache: LruMap<_, _, 3> = LruMap::new();
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// cache.put(3, "c");
+    /// assert_eq!(cache.remove(&2), Some("b"));
+    /// assert_eq!(cache.remove(&2), None);
+    /// assert_eq!(cache.keys().collect::<Vec<_>>(), [&3, &1]);
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let i = self.map.position_of(k)?;
+        self.map.move_to_back(i);
+        self.map.pop_back().map(|(_, v)| v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LruMap;
+
+    #[test]
+    fn put_evicts_least_recent() {
+        let mut cache: LruMap<i32, i32, 2> = LruMap::new();
+        assert_eq!(cache.put(1, 1), None);
+        assert_eq!(cache.put(2, 2), None);
+        assert_eq!(cache.get(&1), Some(&1));
+        assert_eq!(cache.put(3, 3), Some((2, 2)));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.put(4, 4), Some((1, 1)));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&3), Some(&3));
+        assert_eq!(cache.get(&4), Some(&4));
+    }
+
+    #[test]
+    fn put_existing_key_touches_it() {
+        let mut cache: LruMap<&str, i32, 3> = LruMap::new();
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        assert_eq!(cache.put("a", 10), None);
+        assert_eq!(cache.peek("a"), Some(&10));
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), ["a", "c", "b"]);
+        assert_eq!(cache.peek_lru(), Some((&"b", &2)));
+    }
+
+    #[test]
+    fn insert_returns_old_value() {
+        let mut cache: LruMap<&str, i32, 2> = LruMap::new();
+        assert_eq!(cache.insert("a", 1), None);
+        assert_eq!(cache.insert("b", 2), None);
+        assert_eq!(cache.insert("a", 10), Some(1));
+        assert_eq!(cache.insert("c", 3), None);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), ["c", "a"]);
+        let mut empty: LruMap<&str, i32, 0> = LruMap::new();
+        assert_eq!(empty.insert("a", 1), None);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn peek_does_not_touch() {
+        let mut cache: LruMap<String, i32, 2> = LruMap::new();
+        cache.put("a".to_string(), 1);
+        cache.put("b".to_string(), 2);
+        assert_eq!(cache.peek("a"), Some(&1));
+        assert!(cache.contains_key("a"));
+        assert_eq!(cache.put("c".to_string(), 3), Some(("a".to_string(), 1)));
+    }
+
+    #[test]
+    fn remove_keeps_order() {
+        let mut cache: LruMap<i32, i32, 4> = LruMap::new();
+        for i in 0..4 {
+            cache.put(i, i);
+        }
+        assert_eq!(cache.remove(&2), Some(2));
+        assert_eq!(cache.remove(&9), None);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), [3, 1, 0]);
+        assert_eq!(cache.values().copied().collect::<Vec<_>>(), [3, 1, 0]);
+        *cache.get_mut(&0).unwrap() = 100;
+        assert_eq!(cache.iter().next(), Some((&0, &100)));
+    }
+
+    #[test]
+    fn zero_capacity_cache() {
+        let mut cache: LruMap<i32, i32, 0> = LruMap::new();
+        assert_eq!(cache.put(1, 1), Some((1, 1)));
+        assert!(cache.is_empty());
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn drops_evicted_and_remaining_pairs() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut cache: LruMap<i32, Rc<()>, 2> = LruMap::new();
+        for i in 0..5 {
+            cache.put(i, Rc::clone(&v));
+        }
+        assert_eq!(Rc::strong_count(&v), 3);
+        assert_eq!(cache.put(4, Rc::clone(&v)), None);
+        assert_eq!(Rc::strong_count(&v), 3);
+        drop(cache);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -3,30 +3,57 @@
 
 //! A small Map based on a fixed length array which stores key-value pairs directly.
 
+mod append;
+mod bulk;
+mod capacity;
 mod clone;
 mod ctors;
 mod debug;
+mod diff;
 mod display;
 pub(crate) mod drain;
 mod entry;
+mod entry_ref;
 mod eq;
+mod extend;
+mod extract_if;
+mod find;
 mod from;
 mod index;
 mod iterators;
+mod join;
 pub(crate) mod keys;
+mod many;
+mod merge;
 mod methods;
+mod promote;
+mod raw_entry;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod serialization;
+mod slice;
+mod sort;
+mod split;
+#[cfg(test)]
+mod testing;
+mod try_entry;
 mod values;
 
 // re-export
+pub use diff::{Diff, DiffItem};
 pub use drain::Drain;
 pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use entry_ref::{EntryRef, VacantEntryRef};
+pub use extract_if::ExtractIf;
 pub use iterators::{IntoIter, Iter, IterMut};
+pub use join::{InnerJoin, LeftJoin, OuterJoin};
 pub use keys::{IntoKeys, Keys};
+pub use raw_entry::{RawEntryBuilderMut, RawEntryMut, RawVacantEntryMut};
+pub use slice::Slice;
+pub use try_entry::TryEntry;
 pub use values::{IntoValues, Values, ValuesMut};
 
+use crate::DefaultEq;
 use core::mem::MaybeUninit;
 
 /// A faster alternative of [`std::collections::HashMap`].
@@ -54,9 +81,15 @@ use core::mem::MaybeUninit;
 /// into it, it simply panics. Moreover, in the "release" mode it doesn't panic,
 /// but its behaviour is undefined. In the "release" mode all boundary checks
 /// are disabled, for the sake of higher performance.
-pub struct Map<K, V, const N: usize> {
+///
+/// The keys are compared with [`PartialEq`] by default. Another
+/// [`KeyEq`][crate::KeyEq] strategy may be given as the last type argument
+/// `E`, see [`with_key_eq()`][Map::with_key_eq].
+pub struct Map<K, V, const N: usize, E = DefaultEq> {
     /// The next available pair in the array.
     len: usize,
     /// The fixed-size array of key-value pairs.
     pairs: [MaybeUninit<(K, V)>; N],
+    /// The strategy comparing the keys.
+    eq: E,
 }
@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::KeyEq;
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Moves all the pairs out of `other` into `self`, leaving `other` empty.
+    ///
+    /// If a key exists in both maps, its value in `self` is overwritten by
+    /// the one from `other`, like [`insert()`][Map::insert] does.
+    ///
+    /// # Panics
+    /// If there are too many new keys to fit into `self`. All the pairs which
+    /// fit are moved before the panic, and the others stay in `other`. If you
+    /// want to avoid this, use [`try_append()`][Map::try_append] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut a: Map<_, _, 4> = Map::from_iter([(1, "a"), (2, "b")]);
+    /// let mut b = Map::from([(2, "B"), (3, "C")]);
+    /// a.append(&mut b);
+    /// assert_eq!(a, Map::from([(1, "a"), (2, "B"), (3, "C")]));
+    /// assert!(b.is_empty());
+    /// ```
+    #[inline]
+    pub fn append<const M: usize>(&mut self, other: &mut Map<K, V, M, E>) {
+        assert!(
+            self.try_append(other).is_ok(),
+            "No more key-value slot available in the map"
+        );
+    }
+
+    /// Attempts to move all the pairs out of `other` into `self`, with the
+    /// same overwriting as [`append()`][Map::append].
+    ///
+    /// # Errors
+    /// If `self` gets full, the pairs with new keys which could not be moved
+    /// stay in `other`, in their order, and their number is returned in
+    /// [`Err`]. All the others are moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut a: Map<_, _, 2> = Map::from_iter([(1, "a")]);
+    /// let mut b = Map::from([(1, "A"), (2, "B"), (3, "C")]);
+    /// assert_eq!(a.try_append(&mut b), Err(1));
+    /// assert_eq!(a, Map::from([(1, "A"), (2, "B")]));
+    /// assert_eq!(b, Map::from([(3, "C")]));
+    /// ```
+    pub fn try_append<const M: usize>(&mut self, other: &mut Map<K, V, M, E>) -> Result<(), usize> {
+        let len = other.len;
+        // if a comparison panics, the unvisited pairs are leaked, not dropped twice
+        other.len = 0;
+        let mut kept = 0;
+        for i in 0..len {
+            let (k, v) = unsafe { other.item_read(i) };
+            match self.position_of(&k) {
+                Some(j) => unsafe { *self.value_mut(j) = v },
+                None if self.len < N => {
+                    self.push_back(k, v);
+                }
+                None => {
+                    unsafe { other.item_write(kept, (k, v)) };
+                    kept += 1;
+                    other.len = kept;
+                }
+            }
+        }
+        if kept == 0 {
+            Ok(())
+        } else {
+            Err(kept)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use std::rc::Rc;
+
+    #[test]
+    fn appends_with_overwrite() {
+        let mut a: Map<char, u8, 5> = Map::from_iter([('a', 1), ('b', 2)]);
+        let mut b: Map<char, u8, 3> = Map::from([('c', 30), ('b', 20), ('d', 40)]);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [(&'a', &1), (&'b', &20), (&'c', &30), (&'d', &40)]
+        );
+    }
+
+    #[test]
+    fn keeps_rest_in_order() {
+        let mut a: Map<u8, u8, 2> = Map::new();
+        let mut b: Map<u8, u8, 5> = Map::from([(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)]);
+        assert_eq!(a.try_append(&mut b), Err(3));
+        assert_eq!(b.keys().copied().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(a.try_append(&mut Map::<u8, u8, 1>::from([(2, 20)])), Ok(()));
+        assert_eq!(a[&2], 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn append_into_full_map_panics() {
+        let mut a: Map<u8, u8, 1> = Map::from([(1, 1)]);
+        a.append(&mut Map::from([(2, 2)]));
+    }
+
+    #[test]
+    fn drops_overwritten_values() {
+        let v = Rc::new(());
+        let mut a: Map<u8, Rc<()>, 1> = Map::new();
+        a.insert(1, Rc::clone(&v));
+        let mut b: Map<u8, Rc<()>, 2> = Map::new();
+        b.insert(1, Rc::clone(&v));
+        b.insert(2, Rc::clone(&v));
+        assert_eq!(a.try_append(&mut b), Err(1));
+        assert_eq!(Rc::strong_count(&v), 3);
+        drop(b);
+        assert_eq!(Rc::strong_count(&v), 2);
+    }
+}
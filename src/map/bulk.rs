@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::KeyEq;
+
+impl<K, V, const N: usize, E: KeyEq<K> + Default> Map<K, V, N, E> {
+    /// Makes a map from the pairs, whose keys are known to be unique, without
+    /// comparing them, so it takes `O(len)` instead of `O(len²)`.
+    ///
+    /// Only in the `debug` mode, the keys are checked. If they are not unique
+    /// in the `release` mode, the map is not broken in the memory sense, but
+    /// the lookups find only the first of the equal keys.
+    ///
+    /// # Panics
+    /// If there are more than `N` pairs, or, in the `debug` mode, if the keys
+    /// are not unique.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m: Map<u32, char, 26> = Map::from_unique((0..26).zip('a'..='z'));
+    /// assert_eq!(m[&25], 'z');
+    /// ```
+    pub fn from_unique<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut m = Self::default();
+        for (k, v) in iter {
+            debug_assert!(m.position_of(&k).is_none(), "The keys are not unique");
+            m.push_back(k, v);
+        }
+        m
+    }
+}
+
+impl<K: Ord, V, const N: usize> Map<K, V, N> {
+    /// Makes a map from the pairs sorted by their keys, comparing each key
+    /// only with the previous one, so it takes `O(len)` instead of `O(len²)`.
+    ///
+    /// Of the pairs with equal keys the last value is kept, together with the
+    /// first key, like [`insert()`][Map::insert] does. The map keeps the
+    /// sorted order.
+    ///
+    /// # Panics
+    /// If there are more than `N` unique keys, or, in the `debug` mode, if
+    /// the keys are not sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m: Map<_, _, 3> = Map::from_sorted([(1, 'a'), (2, 'b'), (2, 'B'), (3, 'c')]);
+    /// assert_eq!(m.len(), 3);
+    /// assert_eq!(m[&2], 'B');
+    /// ```
+    pub fn from_sorted<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut m = Self::new();
+        for (k, v) in iter {
+            if let Some(last) = m.len.checked_sub(1) {
+                let p = unsafe { m.item_mut(last) };
+                debug_assert!(p.0 <= k, "The keys are not sorted");
+                if p.0 == k {
+                    p.1 = v;
+                    continue;
+                }
+            }
+            m.push_back(k, v);
+        }
+        m
+    }
+}
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Extends the map with the pairs, whose keys are known to be not in the
+    /// map yet. The new keys are compared only with each other, and not with
+    /// the ones already in the map.
+    ///
+    /// Of the new pairs with equal keys the last value is kept, together with
+    /// the first key, like [`insert()`][Map::insert] does. Only in the `debug`
+    /// mode, the new keys are checked against the old ones.
+    ///
+    /// # Panics
+    /// If the map gets full, or, in the `debug` mode, if a new key is in the
+    /// map already.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<_, _, 4> = Map::from_iter([(1, 'a'), (2, 'b')]);
+    /// m.extend_unique([(3, 'c'), (4, 'd'), (3, 'C')]);
+    /// assert_eq!(m.len(), 4);
+    /// assert_eq!(m[&3], 'C');
+    /// ```
+    pub fn extend_unique<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let start = self.len;
+        for (k, v) in iter {
+            debug_assert!(
+                (0..start).all(|i| !self.eq.key_eq(&unsafe { self.item_ref(i) }.0, &k)),
+                "The key is in the map already"
+            );
+            let found =
+                (start..self.len).find(|&i| self.eq.key_eq(&unsafe { self.item_ref(i) }.0, &k));
+            match found {
+                Some(i) => unsafe { *self.value_mut(i) = v },
+                None => {
+                    self.push_back(k, v);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use crate::map::testing::Counted;
+    use crate::AsciiCaseInsensitive;
+    use std::cell::Cell;
+
+    #[test]
+    fn builds_from_unique_keys() {
+        let m: Map<u32, u32, 8> = Map::from_unique((0..8).map(|i| (i, i * i)));
+        assert_eq!(m.len(), 8);
+        assert_eq!(m[&7], 49);
+        let m: Map<&str, u8, 2, AsciiCaseInsensitive> = Map::from_unique([("a", 1)]);
+        assert_eq!(m["A"], 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The keys are not unique")]
+    fn checks_unique_keys_in_debug() {
+        let _: Map<u8, u8, 2> = Map::from_unique([(1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn builds_from_sorted_keys() {
+        let m: Map<u8, char, 4> =
+            Map::from_sorted([(1, 'a'), (1, 'A'), (2, 'b'), (5, 'e'), (5, 'E')]);
+        assert_eq!(m.as_slice(), [(1, 'A'), (2, 'b'), (5, 'E')]);
+        let m: Map<u8, char, 0> = Map::from_sorted([]);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn from_sorted_overflow_panics() {
+        let _: Map<u8, u8, 2> = Map::from_sorted([(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The keys are not sorted")]
+    fn checks_sorted_keys_in_debug() {
+        let _: Map<u8, u8, 2> = Map::from_sorted([(2, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn extends_comparing_only_new_keys() {
+        let calls = Cell::new(0);
+        let mut m: Map<Counted<'_, u32>, u8, 8> =
+            Map::from_unique((0..4).map(|i| (Counted(i, &calls), 0)));
+        calls.set(0);
+        m.extend_unique((4..8).map(|i| (Counted(i, &calls), 1)));
+        assert_eq!(m.len(), 8);
+        // 0 + 1 + 2 + 3 among the new keys, and 4 x 4 debug-only checks
+        // of the new keys against the old ones
+        let checks = if cfg!(debug_assertions) { 16 } else { 0 };
+        assert_eq!(calls.get(), 6 + checks);
+        let mut m: Map<u8, u8, 3> = Map::from_iter([(1, 1)]);
+        m.extend_unique([(2, 2), (3, 3), (2, 20)]);
+        assert_eq!(m.as_slice(), [(1, 1), (2, 20), (3, 3)]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The key is in the map already")]
+    fn checks_disjoint_keys_in_debug() {
+        let mut m: Map<u8, u8, 3> = Map::from_iter([(1, 1)]);
+        m.extend_unique([(1, 2)]);
+    }
+}
@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use core::ptr;
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Moves all the pairs into a map with another capacity `M`, which must
+    /// not be less than `N`, so the pairs always fit.
+    ///
+    /// The pairs are moved as they are, without any comparison of the keys,
+    /// and their order is kept.
+    ///
+    /// It does not compile if `M < N`. Use
+    /// [`try_into_capacity()`][Map::try_into_capacity] to shrink a map.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m: Map<_, _, 2> = Map::from([(1, "a"), (2, "b")]);
+    /// let mut m: Map<_, _, 8> = m.into_capacity();
+    /// m.insert(3, "c");
+    /// assert_eq!(m.len(), 3);
+    /// assert_eq!(m.capacity(), 8);
+    /// ```
+    ///
+    /// A smaller capacity is rejected at compile time:
+    /// ```compile_fail
+    /// use micromap::Map;
+    /// let m: Map<u8, u8, 4> = Map::new();
+    /// let m: Map<u8, u8, 2> = m.into_capacity();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_capacity<const M: usize>(self) -> Map<K, V, M, E> {
+        const { assert!(M >= N, "The new capacity is less than the old one") };
+        let Ok(m) = self.try_into_capacity() else {
+            unreachable!("the pairs always fit into a larger map")
+        };
+        m
+    }
+
+    /// Attempts to move all the pairs into a map with another capacity `M`,
+    /// which may be less than `N`, as long as the pairs fit.
+    ///
+    /// The pairs are moved as they are, without any comparison of the keys,
+    /// and their order is kept.
+    ///
+    /// # Errors
+    /// If there are more than `M` pairs, the map is returned back in [`Err`]
+    /// untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<_, _, 8> = Map::new();
+    /// m.insert(1, "a");
+    /// let m: Map<_, _, 1> = m.try_into_capacity().unwrap();
+    /// assert_eq!(m[&1], "a");
+    /// let m: Map<_, _, 4> = Map::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    /// assert!(m.try_into_capacity::<3>().is_err());
+    /// ```
+    #[inline]
+    pub fn try_into_capacity<const M: usize>(self) -> Result<Map<K, V, M, E>, Self> {
+        if self.len > M {
+            return Err(self);
+        }
+        let (mut from, eq) = self.into_parts();
+        let mut to = Map::with_key_eq(eq);
+        // SAFETY: the first `len` pairs are initialized and fit into `to`,
+        // and `from` forgets them right after, so they are moved, not copied.
+        unsafe {
+            ptr::copy_nonoverlapping(from.pairs.as_ptr(), to.pairs.as_mut_ptr(), from.len);
+        }
+        to.len = from.len;
+        from.len = 0;
+        Ok(to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use crate::AsciiCaseInsensitive;
+    use std::rc::Rc;
+
+    #[test]
+    fn grows_and_keeps_order() {
+        let m: Map<u8, char, 3> = Map::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+        let m: Map<u8, char, 10> = m.into_capacity();
+        assert_eq!(m.capacity(), 10);
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [3, 1, 2]);
+        let m: Map<u8, char, 10> = m.into_capacity();
+        assert_eq!(m.len(), 3);
+    }
+
+    #[test]
+    fn shrinks_only_if_pairs_fit() {
+        let mut m: Map<u8, u8, 4> = Map::new();
+        m.insert(1, 1);
+        m.insert(2, 2);
+        let m = m.try_into_capacity::<1>().unwrap_err();
+        assert_eq!(m.len(), 2);
+        let m: Map<u8, u8, 2> = m.try_into_capacity().unwrap();
+        assert_eq!(m, Map::from([(1, 1), (2, 2)]));
+        let m: Map<u8, u8, 0> = Map::<u8, u8, 2>::new().try_into_capacity().unwrap();
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn moves_pairs_without_dropping() {
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 2> = Map::new();
+        m.insert(1, Rc::clone(&v));
+        m.insert(2, Rc::clone(&v));
+        let m: Map<u8, Rc<()>, 5> = m.into_capacity();
+        assert_eq!(Rc::strong_count(&v), 3);
+        let m = m.try_into_capacity::<1>().unwrap_err();
+        assert_eq!(Rc::strong_count(&v), 3);
+        drop(m);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+
+    #[test]
+    fn keeps_key_eq() {
+        let mut m: Map<&str, u8, 1, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        m.insert("a", 1);
+        let mut m: Map<&str, u8, 2, _> = m.into_capacity();
+        m.insert("A", 2);
+        assert_eq!(m.len(), 1);
+    }
+}
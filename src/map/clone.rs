@@ -3,13 +3,14 @@
 
 use super::Map;
 
-impl<K, V, const N: usize> Clone for Map<K, V, N>
+impl<K, V, const N: usize, E> Clone for Map<K, V, N, E>
 where
     K: Clone,
     V: Clone,
+    E: Clone,
 {
     fn clone(&self) -> Self {
-        let mut m = Self::new();
+        let mut m = Self::with_key_eq(self.eq.clone());
         m.len = self.len;
         m.pairs
             .iter_mut()
@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
+use crate::DefaultEq;
 use core::mem::MaybeUninit;
 
-impl<K, V, const N: usize> Default for Map<K, V, N> {
-    /// Creates a empty [Map] like [`new()`][`Map::new`].
+impl<K, V, const N: usize, E: Default> Default for Map<K, V, N, E> {
+    /// Creates a empty [Map] like [`new()`][`Map::new`], with the default
+    /// key equality strategy.
     #[inline]
     fn default() -> Self {
-        Self::new()
+        Self::with_key_eq(E::default())
     }
 }
 
@@ -32,10 +34,7 @@ impl<K, V, const N: usize> Map<K, V, N> {
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        Self {
-            len: 0,
-            pairs: [const { MaybeUninit::uninit() }; N],
-        }
+        Self::with_key_eq(DefaultEq)
     }
 
     /// Creates an empty [Map] with fixed capacity.
@@ -65,7 +64,36 @@ impl<K, V, const N: usize> Map<K, V, N> {
     }
 }
 
-impl<K, V, const N: usize> Drop for Map<K, V, N> {
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Creates an empty [Map] with capacity `N`, which compares the keys with
+    /// the given [`KeyEq`][crate::KeyEq] strategy instead of [`PartialEq`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::{AsciiCaseInsensitive, Map};
+    /// let mut m: Map<String, u8, 2, _> = Map::with_key_eq(AsciiCaseInsensitive);
+    /// m.insert("Accept".to_string(), 1);
+    /// assert!(m.contains_key("ACCEPT"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_key_eq(eq: E) -> Self {
+        Self {
+            len: 0,
+            pairs: [const { MaybeUninit::uninit() }; N],
+            eq,
+        }
+    }
+
+    /// Returns a reference to the key equality strategy of the map.
+    #[inline]
+    #[must_use]
+    pub const fn key_eq(&self) -> &E {
+        &self.eq
+    }
+}
+
+impl<K, V, const N: usize, E> Drop for Map<K, V, N, E> {
     fn drop(&mut self) {
         for i in 0..self.len {
             unsafe { self.item_drop(i) };
@@ -4,7 +4,7 @@
 use super::Map;
 use core::fmt;
 
-impl<K, V, const N: usize> fmt::Debug for Map<K, V, N>
+impl<K, V, const N: usize, E> fmt::Debug for Map<K, V, N, E>
 where
     K: fmt::Debug,
     V: fmt::Debug,
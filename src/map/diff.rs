@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::join::OuterJoin;
+use super::Map;
+use crate::{DefaultEq, KeyEq};
+use core::iter::FusedIterator;
+
+impl<K, V: PartialEq, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Visits the differences between `self` (as the old state) and `other`
+    /// (as the new state): the added keys, the removed keys and the keys
+    /// whose values changed. The keys with equal values are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::map::DiffItem;
+    /// use micromap::Map;
+    /// let old = Map::from([("a", 1), ("b", 2), ("c", 3)]);
+    /// let new: Map<_, _, 4> = Map::from_iter([("a", 1), ("b", 20), ("d", 4)]);
+    /// let diff: Vec<_> = old.diff(&new).collect();
+    /// assert_eq!(diff.len(), 3);
+    /// assert!(diff.contains(&DiffItem::Changed { key: &"b", old: &2, new: &20 }));
+    /// assert!(diff.contains(&DiffItem::Removed(&"c", &3)));
+    /// assert!(diff.contains(&DiffItem::Added(&"d", &4)));
+    /// ```
+    ///
+    /// # Performance
+    /// Iterating over the whole diff takes O((len + M) * (N + M)) time.
+    #[inline]
+    pub fn diff<'a, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V, M, E>,
+    ) -> Diff<'a, K, V, N, M, E> {
+        Diff {
+            iter: self.outer_join(other),
+        }
+    }
+
+    /// Replays a diff onto the map, so that a map equal to the old state of
+    /// the diff becomes equal to its new state.
+    ///
+    /// # Panics
+    /// If there are too many added keys to fit into the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let old = Map::from([("a", 1), ("b", 2), ("c", 3)]);
+    /// let new = Map::from([("a", 1), ("b", 20), ("d", 4)]);
+    /// let mut replica = old.clone();
+    /// replica.apply_diff(old.diff(&new));
+    /// assert_eq!(replica, new);
+    /// ```
+    pub fn apply_diff<'a, I>(&mut self, diff: I)
+    where
+        I: IntoIterator<Item = DiffItem<'a, K, V>>,
+        K: Clone + 'a,
+        V: Clone + 'a,
+    {
+        for item in diff {
+            match item {
+                DiffItem::Added(k, v) | DiffItem::Changed { key: k, new: v, .. } => {
+                    self.insert(k.clone(), v.clone());
+                }
+                DiffItem::Removed(k, _) => {
+                    self.remove(k);
+                }
+            }
+        }
+    }
+}
+
+/// One difference between two `Map`s, which is yielded by [`Diff`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum DiffItem<'a, K, V> {
+    /// The key exists in the new map only.
+    Added(&'a K, &'a V),
+    /// The key exists in the old map only.
+    Removed(&'a K, &'a V),
+    /// The key exists in both maps, with different values.
+    Changed {
+        /// The key.
+        key: &'a K,
+        /// The value in the old map.
+        old: &'a V,
+        /// The value in the new map.
+        new: &'a V,
+    },
+}
+
+impl<K, V> Clone for DiffItem<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for DiffItem<'_, K, V> {}
+
+impl<'a, K, V> DiffItem<'a, K, V> {
+    /// Returns the key of this difference.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &'a K {
+        match *self {
+            Self::Added(k, _) | Self::Removed(k, _) | Self::Changed { key: k, .. } => k,
+        }
+    }
+}
+
+/// A lazy iterator over the differences between two `Map`s.
+///
+/// This `struct` is created by the [`diff`][Map::diff] method on [`Map`]. See
+/// its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Diff<'a, K, V, const N: usize, const M: usize, E = DefaultEq> {
+    iter: OuterJoin<'a, K, V, V, N, M, E>,
+}
+
+impl<K, V, const N: usize, const M: usize, E> Clone for Diff<'_, K, V, N, M, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, K, V: PartialEq, const N: usize, const M: usize, E: KeyEq<K>> Iterator
+    for Diff<'a, K, V, N, M, E>
+{
+    type Item = DiffItem<'a, K, V>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|item| match item {
+            (k, Some(old), None) => Some(DiffItem::Removed(k, old)),
+            (k, None, Some(new)) => Some(DiffItem::Added(k, new)),
+            (key, Some(old), Some(new)) if old != new => Some(DiffItem::Changed { key, old, new }),
+            _ => None,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<K, V: PartialEq, const N: usize, const M: usize, E: KeyEq<K>> FusedIterator
+    for Diff<'_, K, V, N, M, E>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffItem, Map};
+    use crate::AsciiCaseInsensitive;
+
+    #[test]
+    fn diff_of_different_capacities() {
+        let old: Map<u8, char, 3> = Map::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+        let new: Map<u8, char, 5> = Map::from_iter([(2, 'b'), (3, 'x'), (4, 'd'), (5, 'e')]);
+        let mut keys: Vec<_> = old.diff(&new).map(|d| *d.key()).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, [1, 3, 4, 5]);
+        assert_eq!(
+            old.diff(&new)
+                .filter(|d| matches!(d, DiffItem::Added(..)))
+                .count(),
+            2
+        );
+        assert_eq!(new.diff(&new).count(), 0);
+        assert_eq!(old.diff(&new).clone().size_hint(), (0, Some(7)));
+    }
+
+    #[test]
+    fn apply_diff_in_both_directions() {
+        let a = Map::from([("x", 1), ("y", 2)]);
+        let b = Map::from([("y", 3), ("z", 4)]);
+        let mut m = a.clone();
+        m.apply_diff(a.diff(&b));
+        assert_eq!(m, b);
+        m.apply_diff(b.diff(&a));
+        assert_eq!(m, a);
+        let copied: Vec<_> = a.diff(&b).collect();
+        let mut n = a.clone();
+        n.apply_diff(copied.iter().copied());
+        assert_eq!(n, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    #[cfg(debug_assertions)]
+    fn apply_diff_overflow_panics() {
+        let a: Map<u8, u8, 1> = Map::from([(1, 1)]);
+        let b: Map<u8, u8, 2> = Map::from([(2, 2), (3, 3)]);
+        let mut m = a.clone();
+        m.apply_diff(a.diff(&b));
+    }
+
+    #[test]
+    fn diff_and_apply_with_key_eq() {
+        let mut old: Map<&str, u8, 3, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        old.insert("Host", 1);
+        old.insert("Accept", 2);
+        let mut new: Map<&str, u8, 3, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        new.insert("HOST", 1);
+        new.insert("accept", 20);
+        new.insert("Range", 3);
+        let diff: Vec<_> = old.diff(&new).collect();
+        assert_eq!(diff.len(), 2);
+        assert!(diff.contains(&DiffItem::Changed {
+            key: &"Accept",
+            old: &2,
+            new: &20
+        }));
+        assert!(diff.contains(&DiffItem::Added(&"Range", &3)));
+        let mut m = old.clone();
+        m.apply_diff(diff);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m["ACCEPT"], 20);
+        assert_eq!(m["range"], 3);
+    }
+}
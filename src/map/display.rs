@@ -5,7 +5,7 @@ use super::Map;
 use core::fmt;
 use core::fmt::Write;
 
-impl<K, V, const N: usize> fmt::Display for Map<K, V, N>
+impl<K, V, const N: usize, E> fmt::Display for Map<K, V, N, E>
 where
     K: fmt::Display,
     V: fmt::Display,
@@ -5,7 +5,7 @@ use super::iterators::slice_iter;
 use super::Map;
 use core::{fmt, iter::FusedIterator, mem::MaybeUninit};
 
-impl<K, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
     /// Clears the map, returning all key-value pairs as an iterator. For reuse, the
     /// memory of capacity `N` will be keeped.
     ///
@@ -89,6 +89,15 @@ impl<K, V> Iterator for Drain<'_, K, V> {
     }
 }
 
+impl<K, V> DoubleEndedIterator for Drain<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.iter
+            .next_back()
+            .map(|p| unsafe { p.assume_init_read() })
+    }
+}
+
 impl<K, V> ExactSizeIterator for Drain<'_, K, V> {
     #[inline]
     fn len(&self) -> usize {
@@ -126,6 +135,20 @@ mod tests {
         drop(drain);
     }
 
+    #[test]
+    fn drain_from_both_ends() {
+        let mut map = Map::<char, u8, 4>::from_iter([('a', 97), ('b', 98), ('c', 99)]);
+        let mut drain = map.drain();
+        assert_eq!(drain.next_back(), Some(('c', 99)));
+        assert_eq!(drain.next(), Some(('a', 97)));
+        assert_eq!(drain.len(), 1);
+        assert_eq!(drain.next_back(), Some(('b', 98)));
+        assert!(drain.next_back().is_none());
+        assert!(drain.next().is_none());
+        drop(drain);
+        assert!(map.is_empty());
+    }
+
     #[test]
     fn debug_trait_for_drain() {
         let mut map = Map::from([('x', 120), ('y', 121), ('z', 122)]);
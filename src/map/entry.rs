@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
+use crate::{DefaultEq, KeyEq};
 use core::mem;
 
-impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
     /// Gets the given key’s corresponding entry in the map for in-place
     /// manipulation.
     ///
@@ -20,11 +21,11 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     /// assert_eq!(letters[&'u'], 1);
     /// assert_eq!(letters.get(&'y'), None);
     /// ```
-    pub fn entry(&mut self, k: K) -> Entry<'_, K, V, N> {
+    pub fn entry(&mut self, k: K) -> Entry<'_, K, V, N, E> {
         if let Some((i, _)) = self.pairs[..self.len]
             .iter()
             .enumerate()
-            .find(|(_, p)| unsafe { p.assume_init_ref() }.0 == k)
+            .find(|(_, p)| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, &k))
         {
             Entry::Occupied(OccupiedEntry {
                 index: i,
@@ -42,28 +43,28 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
 /// A view into a single entry in a map, which may either be vacant or occupied.
 ///
 /// This `enum` is constructed from the [`entry`][Map::entry] method on [`Map`].
-pub enum Entry<'a, K, V, const N: usize> {
+pub enum Entry<'a, K, V, const N: usize, E = DefaultEq> {
     /// An occupied entry.
-    Occupied(OccupiedEntry<'a, K, V, N>),
+    Occupied(OccupiedEntry<'a, K, V, N, E>),
     /// A vacant entry.
-    Vacant(VacantEntry<'a, K, V, N>),
+    Vacant(VacantEntry<'a, K, V, N, E>),
 }
 
 /// A view into an occupied entry in a `Map`.
 /// It is part of the [`Entry`] enum.
-pub struct OccupiedEntry<'a, K, V, const N: usize> {
-    index: usize,
-    table: &'a mut Map<K, V, N>,
+pub struct OccupiedEntry<'a, K, V, const N: usize, E = DefaultEq> {
+    pub(super) index: usize,
+    pub(super) table: &'a mut Map<K, V, N, E>,
 }
 
 /// A view into a vacant entry in a `Map`.
 /// It is part of the [`Entry`] enum.
-pub struct VacantEntry<'a, K, V, const N: usize> {
-    key: K,
-    table: &'a mut Map<K, V, N>,
+pub struct VacantEntry<'a, K, V, const N: usize, E = DefaultEq> {
+    pub(super) key: K,
+    pub(super) table: &'a mut Map<K, V, N, E>,
 }
 
-impl<K, V, const N: usize> Entry<'_, K, V, N> {
+impl<K, V, const N: usize, E> Entry<'_, K, V, N, E> {
     /// Returns a reference to this entry's key.
     ///
     /// # Examples
@@ -113,7 +114,7 @@ impl<K, V, const N: usize> Entry<'_, K, V, N> {
     }
 }
 
-impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
+impl<'a, K, V, const N: usize, E: KeyEq<K>> Entry<'a, K, V, N, E> {
     /// Ensures a value is in the entry by inserting the default if empty, and
     /// returns a mutable reference to the value in the entry.
     ///
@@ -154,6 +155,31 @@ impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry. Unlike [`or_insert_with()`][Self::or_insert_with], it does not
+    /// panic on a full map.
+    ///
+    /// # Errors
+    /// If the entry is vacant and the map is full already, the key and the
+    /// result of the default function are returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut map: Map<&str, u32, 1> = Map::new();
+    /// assert_eq!(map.entry("a").or_try_insert_with(|| 1), Ok(&mut 1));
+    /// assert_eq!(map.entry("a").or_try_insert_with(|| 2), Ok(&mut 1));
+    /// assert_eq!(map.entry("b").or_try_insert_with(|| 3), Err(("b", 3)));
+    /// ```
+    #[inline]
+    pub fn or_try_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V, (K, V)> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.try_insert(default()),
+        }
+    }
+
     /// Ensures a value is in the entry by inserting, if empty, the result of
     /// the default function.
     ///
@@ -192,7 +218,7 @@ impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
     /// assert_eq!(entry.key(), &"poneyland");
     /// ```
     #[inline]
-    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N> {
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N, E> {
         match self {
             Entry::Occupied(mut entry) => {
                 entry.insert(value);
@@ -203,7 +229,7 @@ impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
     }
 }
 
-impl<'a, K: PartialEq, V: Default, const N: usize> Entry<'a, K, V, N> {
+impl<'a, K, V: Default, const N: usize, E: KeyEq<K>> Entry<'a, K, V, N, E> {
     /// Ensures a value is in the entry by inserting the default value if empty,
     /// and returns a mutable reference to the value in the entry.
     ///
@@ -223,7 +249,7 @@ impl<'a, K: PartialEq, V: Default, const N: usize> Entry<'a, K, V, N> {
     }
 }
 
-impl<'a, K, V, const N: usize> OccupiedEntry<'a, K, V, N> {
+impl<'a, K, V, const N: usize, E> OccupiedEntry<'a, K, V, N, E> {
     /// Gets a reference to the key in the entry.
     ///
     /// # Examples
@@ -263,6 +289,13 @@ impl<'a, K, V, const N: usize> OccupiedEntry<'a, K, V, N> {
         unsafe { self.table.value_mut(self.index) }
     }
 
+    /// Internal function to convert the entry into a reference to the key
+    /// with a lifetime bound to the map itself.
+    #[inline]
+    pub(crate) fn into_key_ref(self) -> &'a K {
+        unsafe { &self.table.item_ref(self.index).0 }
+    }
+
     /// Gets a reference to the value in the entry.
     ///
     /// # Examples
@@ -367,7 +400,7 @@ impl<'a, K, V, const N: usize> OccupiedEntry<'a, K, V, N> {
     }
 }
 
-impl<K, V, const N: usize> VacantEntry<'_, K, V, N> {
+impl<K, V, const N: usize, E> VacantEntry<'_, K, V, N, E> {
     /// Gets a reference to the key that would be used when inserting a
     /// value through the `VacantEntry`.
     ///
@@ -399,9 +432,15 @@ impl<K, V, const N: usize> VacantEntry<'_, K, V, N> {
     pub fn into_key(self) -> K {
         self.key
     }
+
+    /// Internal function to check whether a new key can be inserted.
+    #[inline]
+    pub(crate) fn is_full(&self) -> bool {
+        self.table.len() == N
+    }
 }
 
-impl<'a, K: PartialEq, V, const N: usize> VacantEntry<'a, K, V, N> {
+impl<'a, K, V, const N: usize, E: KeyEq<K>> VacantEntry<'a, K, V, N, E> {
     /// Sets the value of the entry with the `VacantEntry`'s key,
     /// and returns a mutable reference to it.
     ///
@@ -421,6 +460,34 @@ impl<'a, K: PartialEq, V, const N: usize> VacantEntry<'a, K, V, N> {
         unsafe { self.table.value_mut(index) }
     }
 
+    /// Attempts to set the value of the entry with the `VacantEntry`'s key,
+    /// and returns a mutable reference to it.
+    ///
+    /// # Errors
+    /// If the map is full already, the key and the value are returned back
+    /// in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// use micromap::map::Entry;
+    /// let mut map: Map<&str, u32, 1> = Map::new();
+    /// if let Entry::Vacant(v) = map.entry("a") {
+    ///     assert_eq!(v.try_insert(1), Ok(&mut 1));
+    /// }
+    /// if let Entry::Vacant(v) = map.entry("b") {
+    ///     assert_eq!(v.try_insert(2), Err(("b", 2)));
+    /// }
+    /// ```
+    #[inline]
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, (K, V)> {
+        if self.is_full() {
+            return Err((self.key, value));
+        }
+        let i = self.table.push_back(self.key, value);
+        Ok(unsafe { self.table.value_mut(i) })
+    }
+
     /// Sets the value of the entry with the `VacantEntry`'s key,
     /// and returns an `OccupiedEntry`.
     ///
@@ -435,7 +502,7 @@ impl<'a, K: PartialEq, V, const N: usize> VacantEntry<'a, K, V, N> {
     /// assert_eq!(map["poneyland"], 37);
     /// ```
     #[inline]
-    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N> {
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N, E> {
         let (i, pair) = self.table.insert_ii(self.key, value, false);
         debug_assert!(pair.is_none());
         OccupiedEntry {
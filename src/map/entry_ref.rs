@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::entry::OccupiedEntry;
+use super::Map;
+use crate::{DefaultEq, KeyEq};
+use core::borrow::Borrow;
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Gets the given key's corresponding entry by reference in the map for
+    /// in-place manipulation.
+    ///
+    /// Unlike [`entry()`][Map::entry], it takes a borrowed key, and the owned
+    /// key is built with [`From`] only when a vacant entry is inserted into.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut words: Map<String, usize, 8> = Map::new();
+    /// for w in "the cat and the hat".split(' ') {
+    ///     *words.entry_ref(w).or_insert(0) += 1; // a `String` is built only 4 times
+    /// }
+    /// assert_eq!(words["the"], 2);
+    /// assert_eq!(words["cat"], 1);
+    /// ```
+    #[inline]
+    pub fn entry_ref<'a, 'b, Q>(&'a mut self, k: &'b Q) -> EntryRef<'a, 'b, K, Q, V, N, E>
+    where
+        K: Borrow<Q>,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        match self.position_of(k) {
+            Some(index) => EntryRef::Occupied(OccupiedEntry { index, table: self }),
+            None => EntryRef::Vacant(VacantEntryRef {
+                key: k,
+                table: self,
+            }),
+        }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or
+/// occupied, looked up by a borrowed key.
+///
+/// This `enum` is constructed from the [`entry_ref`][Map::entry_ref] method
+/// on [`Map`].
+pub enum EntryRef<'a, 'b, K, Q: ?Sized, V, const N: usize, E = DefaultEq> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N, E>),
+    /// A vacant entry.
+    Vacant(VacantEntryRef<'a, 'b, K, Q, V, N, E>),
+}
+
+/// A view into a vacant entry in a `Map`, holding a borrowed key.
+/// It is part of the [`EntryRef`] enum.
+pub struct VacantEntryRef<'a, 'b, K, Q: ?Sized, V, const N: usize, E = DefaultEq> {
+    key: &'b Q,
+    table: &'a mut Map<K, V, N, E>,
+}
+
+impl<K, Q, V, const N: usize, E> EntryRef<'_, '_, K, Q, V, N, E>
+where
+    K: Borrow<Q>,
+    Q: ?Sized,
+{
+    /// Returns a reference to this entry's key.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut map: Map<String, u32, 3> = Map::new();
+    /// assert_eq!(map.entry_ref("poneyland").key(), "poneyland");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn key(&self) -> &Q {
+        match self {
+            EntryRef::Occupied(entry) => entry.key().borrow(),
+            EntryRef::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    #[inline]
+    #[allow(clippy::return_self_not_must_use)] // function has side effects (impure)
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            EntryRef::Occupied(mut entry) => {
+                f(entry.get_mut());
+                EntryRef::Occupied(entry)
+            }
+            EntryRef::Vacant(entry) => EntryRef::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, 'b, K, Q, V, const N: usize, E> EntryRef<'a, 'b, K, Q, V, N, E>
+where
+    K: Borrow<Q> + From<&'b Q>,
+    Q: ?Sized,
+{
+    /// Ensures a value is in the entry by inserting the default if empty, and
+    /// returns a mutable reference to the value in the entry.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the map is full already.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the map is full already.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of
+    /// the default function, which gets a reference to the borrowed key.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the map is full already.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut map: Map<String, usize, 3> = Map::new();
+    /// map.entry_ref("poneyland").or_insert_with_key(|key| key.len());
+    /// assert_eq!(map["poneyland"], 9);
+    /// ```
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&Q) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => {
+                let value = default(entry.key);
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default value if
+    /// empty, and returns a mutable reference to the value in the entry.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the map is full already.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'b, K, Q: ?Sized, V, const N: usize, E> VacantEntryRef<'_, 'b, K, Q, V, N, E> {
+    /// Gets a reference to the borrowed key, which would be converted into
+    /// the owned one when inserting a value.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &'b Q {
+        self.key
+    }
+}
+
+impl<'a, 'b, K, Q, V, const N: usize, E> VacantEntryRef<'a, 'b, K, Q, V, N, E>
+where
+    K: From<&'b Q>,
+    Q: ?Sized,
+{
+    /// Sets the value of the entry with the owned key built from the
+    /// borrowed one, and returns a mutable reference to it.
+    ///
+    /// # Panics
+    /// If the map is full already.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// use micromap::map::EntryRef;
+    /// let mut map: Map<String, u32, 3> = Map::new();
+    /// if let EntryRef::Vacant(v) = map.entry_ref("poneyland") {
+    ///     v.insert(37);
+    /// }
+    /// assert_eq!(map["poneyland"], 37);
+    /// ```
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let i = self.table.push_back(K::from(self.key), value);
+        unsafe { self.table.value_mut(i) }
+    }
+
+    /// Sets the value of the entry with the owned key built from the
+    /// borrowed one, and returns an `OccupiedEntry`.
+    ///
+    /// # Panics
+    /// If the map is full already.
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, N, E> {
+        let index = self.table.push_back(K::from(self.key), value);
+        OccupiedEntry {
+            index,
+            table: self.table,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntryRef, Map};
+
+    /// A key which counts how many times it was built from a `&str`.
+    #[derive(PartialEq)]
+    struct Counted(String);
+
+    impl core::borrow::Borrow<str> for Counted {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    static BUILT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    impl From<&str> for Counted {
+        fn from(s: &str) -> Self {
+            BUILT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Self(s.to_owned())
+        }
+    }
+
+    #[test]
+    fn builds_owned_key_only_on_insert() {
+        let mut m: Map<Counted, u32, 4> = Map::new();
+        for w in ["a", "b", "a", "a", "c", "b"] {
+            m.entry_ref(w).and_modify(|v| *v += 1).or_insert(1);
+        }
+        assert_eq!(BUILT.load(std::sync::atomic::Ordering::Relaxed), 3);
+        assert_eq!(m.get("a"), Some(&3));
+        assert_eq!(m.get("b"), Some(&2));
+        assert_eq!(m.entry_ref("d").key(), "d");
+        assert_eq!(m.entry_ref("c").key(), "c");
+    }
+
+    #[test]
+    fn vacant_and_occupied() {
+        let mut m: Map<String, u32, 2> = Map::new();
+        let EntryRef::Vacant(v) = m.entry_ref("x") else {
+            unreachable!("the map is empty");
+        };
+        assert_eq!(v.key(), "x");
+        let mut o = v.insert_entry(1);
+        *o.get_mut() += 1;
+        assert_eq!(m["x"], 2);
+        *m.entry_ref("y").or_default() += 5;
+        assert_eq!(m.entry_ref("y").or_insert_with_key(|_| unreachable!()), &5);
+        assert!(matches!(m.entry_ref("x"), EntryRef::Occupied(_)));
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn insert_into_full_map_panics() {
+        let mut m: Map<String, u32, 1> = Map::new();
+        m.entry_ref("a").or_insert(1);
+        m.entry_ref("b").or_insert(2);
+    }
+}
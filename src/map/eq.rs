@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
+use crate::KeyEq;
 
-impl<K: PartialEq, V: PartialEq, const N: usize, const M: usize> PartialEq<Map<K, V, M>>
-    for Map<K, V, N>
+impl<K, V: PartialEq, const N: usize, const M: usize, E: KeyEq<K>> PartialEq<Map<K, V, M, E>>
+    for Map<K, V, N, E>
 {
     /// Two maps can be compared. (The capacity does not affect comparison.)
     ///
@@ -23,12 +24,12 @@ impl<K: PartialEq, V: PartialEq, const N: usize, const M: usize> PartialEq<Map<K
     /// assert_eq!(m1, m2);
     /// ```
     #[inline]
-    fn eq(&self, other: &Map<K, V, M>) -> bool {
+    fn eq(&self, other: &Map<K, V, M, E>) -> bool {
         self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
     }
 }
 
-impl<K: Eq, V: Eq, const N: usize> Eq for Map<K, V, N> {}
+impl<K: Eq, V: Eq, const N: usize, E: KeyEq<K>> Eq for Map<K, V, N, E> {}
 
 #[cfg(test)]
 mod tests {
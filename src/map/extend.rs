@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::KeyEq;
+
+impl<K, V, const N: usize, E: KeyEq<K>> Extend<(K, V)> for Map<K, V, N, E> {
+    /// Inserts all the pairs of an iterator, like [`insert()`][Map::insert]
+    /// does, so the last value of a repeated key wins.
+    ///
+    /// # Panics
+    /// If there are too many new keys to fit into the map. If you want to
+    /// avoid this, use [`try_extend()`][Map::try_extend] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<_, _, 4> = Map::from_iter([("a", 1)]);
+    /// m.extend([("a", 2), ("b", 3), ("a", 4)]);
+    /// assert_eq!(m, Map::from([("a", 4), ("b", 3)]));
+    /// ```
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+impl<'a, K, V, const N: usize, E> Extend<(&'a K, &'a V)> for Map<K, V, N, E>
+where
+    K: 'a + Copy,
+    V: 'a + Copy,
+    E: KeyEq<K>,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|(k, v)| (*k, *v)));
+    }
+}
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Inserts the pairs of an iterator, like [`extend()`][Extend::extend],
+    /// until a pair with a new key does not fit into the map.
+    ///
+    /// # Errors
+    /// If the map is full and a pair with a new key comes, this pair is
+    /// returned in [`Err`] together with the unconsumed rest of the iterator.
+    /// All the pairs before it are inserted.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<_, _, 2> = Map::new();
+    /// let (pair, mut rest) = m.try_extend([(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (4, 'e')])
+    ///     .unwrap_err();
+    /// assert_eq!(m, Map::from([(1, 'c'), (2, 'b')]));
+    /// assert_eq!(pair, (3, 'd'));
+    /// assert_eq!(rest.next(), Some((4, 'e')));
+    /// ```
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), ((K, V), I::IntoIter)>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut iter = iter.into_iter();
+        while let Some((k, v)) = iter.next() {
+            if self.len < N {
+                self.insert(k, v);
+            } else if let Some(i) = self.position_of(&k) {
+                *unsafe { self.value_mut(i) } = v;
+            } else {
+                return Err(((k, v), iter));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+
+    #[test]
+    fn extend_with_pairs_and_refs() {
+        let mut m: Map<u8, char, 4> = Map::new();
+        m.extend([(1, 'a'), (2, 'b')]);
+        let other = Map::from([(2, 'x'), (3, 'y')]);
+        m.extend(&other);
+        assert_eq!(m, Map::from([(1, 'a'), (2, 'x'), (3, 'y')]));
+    }
+
+    #[test]
+    fn try_extend_within_capacity() {
+        let mut m: Map<u8, u8, 3> = Map::new();
+        assert!(m.try_extend((0..3).map(|x| (x, x))).is_ok());
+        assert!(m.try_extend([(0, 10), (2, 12)]).is_ok());
+        assert_eq!(m, Map::from([(0, 10), (1, 1), (2, 12)]));
+    }
+
+    #[test]
+    fn try_extend_stops_at_first_overflow() {
+        let mut m: Map<u8, u8, 1> = Map::new();
+        let Err((pair, rest)) = m.try_extend((0..5).map(|x| (x, x))) else {
+            unreachable!("the map has one slot only");
+        };
+        assert_eq!(pair, (1, 1));
+        assert_eq!(rest.count(), 3);
+        assert_eq!(m, Map::from([(0, 0)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    #[cfg(debug_assertions)]
+    fn extend_overflow_panics() {
+        let mut m: Map<u8, u8, 1> = Map::new();
+        m.extend([(1, 1), (2, 2)]);
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::DefaultEq;
+use core::iter::FusedIterator;
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Creates an iterator which uses a closure to determine if a pair should
+    /// be removed. If the closure returns `true`, the pair is removed from the
+    /// map and yielded. If the closure returns `false`, or panics, the pair
+    /// remains in the map and will not be yielded.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the
+    /// rest pairs are kept in the map, whether they match or not. Use
+    /// [`retain()`][Map::retain] with a negated predicate if you do not need
+    /// the removed pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<u32, u32, 8> = (0..8).map(|x| (x, x * 10)).collect();
+    /// let mut evens: Vec<_> = m.extract_if(|k, _| k % 2 == 0).collect();
+    /// evens.sort_unstable();
+    /// assert_eq!(evens, [(0, 0), (2, 20), (4, 40), (6, 60)]);
+    /// assert_eq!(m.len(), 4);
+    /// ```
+    ///
+    /// # Performance
+    /// Iterating over the whole map takes O(len) time.
+    #[inline]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, N, E>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        ExtractIf {
+            map: self,
+            pred,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator which removes and yields the pairs of a `Map` matching a
+/// predicate.
+///
+/// This `struct` is created by the [`extract_if`][Map::extract_if] method on
+/// [`Map`]. See its documentation for more.
+///
+/// # Example
+/// ```
+/// use micromap::Map;
+/// let mut m = Map::from([("a", 1), ("b", 2), ("c", 3)]);
+/// let first = m.extract_if(|_, v| *v > 1).next();
+/// assert!(first.is_some());
+/// assert_eq!(m.len(), 2); // the other matching pair is kept
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExtractIf<'a, K, V, F, const N: usize, E = DefaultEq> {
+    map: &'a mut Map<K, V, N, E>,
+    pred: F,
+    /// The index of the next pair to be checked.
+    index: usize,
+}
+
+impl<K, V, F, const N: usize, E> Iterator for ExtractIf<'_, K, V, F, N, E>
+where
+    F: FnMut(&K, &mut V) -> bool,
+{
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        while self.index < self.map.len {
+            let (k, v) = unsafe { self.map.item_mut(self.index) };
+            if (self.pred)(k, v) {
+                // the last pair is swapped here, so check the same index again
+                return Some(unsafe { self.map.remove_index_read(self.index) });
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len - self.index))
+    }
+}
+
+impl<K, V, F, const N: usize, E> FusedIterator for ExtractIf<'_, K, V, F, N, E> where
+    F: FnMut(&K, &mut V) -> bool
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+
+    #[test]
+    fn extracts_all_matching_pairs() {
+        let mut m: Map<u32, u32, 10> = (0..10).map(|x| (x, x)).collect();
+        let mut it = m.extract_if(|k, v| {
+            *v += 100;
+            k % 3 != 1
+        });
+        assert_eq!(it.size_hint(), (0, Some(10)));
+        let mut out: Vec<_> = it.by_ref().map(|(k, _)| k).collect();
+        assert!(it.next().is_none());
+        out.sort_unstable();
+        assert_eq!(out, [0, 2, 3, 5, 6, 8, 9]);
+        let mut rest: Vec<_> = m.iter().map(|(k, v)| (*k, *v)).collect();
+        rest.sort_unstable();
+        assert_eq!(rest, [(1, 101), (4, 104), (7, 107)]);
+    }
+
+    #[test]
+    fn keeps_the_rest_when_dropped_early() {
+        let mut m: Map<u32, u32, 6> = (0..6).map(|x| (x, x)).collect();
+        assert_eq!(m.extract_if(|_, _| true).take(2).count(), 2);
+        assert_eq!(m.len(), 4);
+        assert_eq!(m.extract_if(|_, _| false).count(), 0);
+        assert_eq!(m.len(), 4);
+    }
+
+    #[test]
+    fn drops_nothing_it_does_not_yield() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<u32, Rc<()>, 4> = (0..4).map(|x| (x, Rc::clone(&v))).collect();
+        let taken: Vec<_> = m.extract_if(|k, _| *k < 2).collect();
+        assert_eq!(Rc::strong_count(&v), 5);
+        drop(taken);
+        assert_eq!(Rc::strong_count(&v), 3);
+        drop(m);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Returns the first pair matching the predicate, which can look at any
+    /// part of the key and the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([((1, 'a'), "x"), ((2, 'b'), "y")]);
+    /// assert_eq!(m.find(|k, _| k.1 == 'b'), Some((&(2, 'b'), &"y")));
+    /// assert_eq!(m.find(|_, v| *v == "z"), None);
+    /// ```
+    #[inline]
+    pub fn find<F: FnMut(&K, &V) -> bool>(&self, mut f: F) -> Option<(&K, &V)> {
+        self.iter().find(|(k, v)| f(k, v))
+    }
+
+    /// Returns the first pair matching the predicate, with a mutable
+    /// reference to the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([((1, 'a'), 10), ((2, 'b'), 20)]);
+    /// if let Some((_, v)) = m.find_mut(|k, _| k.1 == 'a') {
+    ///     *v += 1;
+    /// }
+    /// assert_eq!(m[&(1, 'a')], 11);
+    /// ```
+    #[inline]
+    pub fn find_mut<F: FnMut(&K, &V) -> bool>(&mut self, mut f: F) -> Option<(&K, &mut V)> {
+        self.iter_mut().find(|(k, v)| f(k, v))
+    }
+
+    /// Returns the index of the first pair matching the predicate, in the
+    /// order of [`iter()`][Map::iter].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([("a", 1), ("b", 2)]);
+    /// let i = m.position(|_, v| *v == 2).unwrap();
+    /// assert_eq!(m.iter().nth(i), Some((&"b", &2)));
+    /// ```
+    #[inline]
+    pub fn position<F: FnMut(&K, &V) -> bool>(&self, mut f: F) -> Option<usize> {
+        self.iter().position(|(k, v)| f(k, v))
+    }
+
+    /// Removes the first pair matching the predicate, returning it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([((1, 'a'), "x"), ((2, 'b'), "y")]);
+    /// assert_eq!(m.remove_by(|k, _| k.0 == 2), Some(((2, 'b'), "y")));
+    /// assert_eq!(m.remove_by(|k, _| k.0 == 2), None);
+    /// assert_eq!(m.len(), 1);
+    /// ```
+    #[inline]
+    pub fn remove_by<F: FnMut(&K, &V) -> bool>(&mut self, f: F) -> Option<(K, V)> {
+        let i = self.position(f)?;
+        Some(unsafe { self.remove_index_read(i) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+
+    #[test]
+    fn finds_by_part_of_key() {
+        let mut m: Map<(u8, &str), u32, 4> =
+            Map::from_iter([((1, "x"), 10), ((2, "y"), 20), ((3, "x"), 30)]);
+        assert_eq!(m.find(|k, _| k.1 == "y").map(|(_, v)| *v), Some(20));
+        assert_eq!(m.position(|k, v| k.1 == "z" || *v > 100), None);
+        for _ in 0..2 {
+            let (_, v) = m.find_mut(|k, v| k.1 == "x" && *v < 100).unwrap();
+            *v += 100;
+        }
+        assert!(m.find_mut(|_, v| *v < 100).is_some());
+        assert_eq!(m.remove_by(|_, v| *v < 100), Some(((2, "y"), 20)));
+        assert!(m.iter().all(|(_, v)| *v > 100));
+        assert_eq!(m.len(), 2);
+    }
+}
@@ -2,11 +2,12 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
+use crate::KeyEq;
 
-impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for Map<K, V, N> {
+impl<K, V, const N: usize, E: KeyEq<K> + Default> FromIterator<(K, V)> for Map<K, V, N, E> {
     #[inline]
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-        let mut m: Self = Self::new();
+        let mut m: Self = Self::default();
         for (k, v) in iter {
             m.insert(k, v);
         }
@@ -14,6 +15,11 @@ impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for Map<K, V, N> {
     }
 }
 
+/// Like [`From`] for the `HashMap` of `std`, this is only for the default
+/// [`KeyEq`] strategy, since a generic one would leave the strategy of
+/// `Map::from([...])` to be inferred, and break the plain calls. A map with
+/// another strategy is built from an array with
+/// [`from_iter()`][FromIterator::from_iter].
 impl<K: PartialEq, V, const N: usize> From<[(K, V); N]> for Map<K, V, N> {
     #[inline]
     fn from(arr: [(K, V); N]) -> Self {
@@ -55,6 +61,15 @@ mod tests {
         assert_eq!(m.len(), 5);
     }
 
+    #[test]
+    fn from_array_with_key_eq() {
+        let m: Map<&str, u8, 3, crate::AsciiCaseInsensitive> =
+            Map::from_iter([("a", 1), ("B", 2), ("A", 3)]);
+        assert_eq!(m.len(), 2);
+        assert_eq!(m["b"], 2);
+        assert_eq!(m["a"], 3);
+    }
+
     #[test]
     fn from_with_duplicates() {
         let arr = [(1, "sun"), (2, "mon"), (3, "tue"), (1, "wed"), (2, "thu")];
@@ -2,12 +2,10 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
-use core::borrow::Borrow;
+use crate::KeyEq;
 use core::ops::{Index, IndexMut};
 
-impl<K: PartialEq + Borrow<Q>, Q: PartialEq + ?Sized, V, const N: usize> Index<&Q>
-    for Map<K, V, N>
-{
+impl<K, Q: ?Sized, V, const N: usize, E: KeyEq<K> + KeyEq<K, Q>> Index<&Q> for Map<K, V, N, E> {
     type Output = V;
 
     #[inline]
@@ -16,9 +14,7 @@ impl<K: PartialEq + Borrow<Q>, Q: PartialEq + ?Sized, V, const N: usize> Index<&
     }
 }
 
-impl<K: PartialEq + Borrow<Q>, Q: PartialEq + ?Sized, V, const N: usize> IndexMut<&Q>
-    for Map<K, V, N>
-{
+impl<K, Q: ?Sized, V, const N: usize, E: KeyEq<K> + KeyEq<K, Q>> IndexMut<&Q> for Map<K, V, N, E> {
     #[inline]
     fn index_mut(&mut self, key: &Q) -> &mut V {
         self.get_mut(key).expect("No entry found for the key")
@@ -28,6 +24,8 @@ impl<K: PartialEq + Borrow<Q>, Q: PartialEq + ?Sized, V, const N: usize> IndexMu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Equivalent;
+    use core::borrow::Borrow;
 
     #[test]
     fn index() {
@@ -71,4 +69,20 @@ mod tests {
         m.insert(Container { t: 10 }, 42);
         assert_eq!(m[&10], 42);
     }
+
+    struct Query<'a>(&'a str, u8);
+
+    impl Equivalent<(String, u8)> for Query<'_> {
+        fn equivalent(&self, key: &(String, u8)) -> bool {
+            self.0 == key.0 && self.1 == key.1
+        }
+    }
+
+    #[test]
+    fn index_by_equivalent() {
+        let mut m: Map<(String, u8), i32, 10> = Map::new();
+        m.insert(("first".to_string(), 1), 42);
+        m[&Query("first", 1)] += 1;
+        assert_eq!(m[&Query("first", 1)], 43);
+    }
 }
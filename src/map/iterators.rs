@@ -4,7 +4,7 @@
 use super::Map;
 use core::{fmt, iter::FusedIterator, mem::MaybeUninit};
 
-impl<K, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
     /// An iterator visiting all key-value pairs in _non-deterministic order_.
     /// The iterator element type is `(&'a K, &'a V)`.
     ///
@@ -120,10 +120,20 @@ pub struct IterMut<'a, K, V> {
 /// }
 /// // assert_eq!(map.len(), 2); // `into_iter()` takes ownership, so can not do this
 /// ```
-#[repr(transparent)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct IntoIter<K, V, const N: usize> {
     pub(super) map: Map<K, V, N>,
+    /// The pairs at `[0, head)` are already taken by
+    /// [`next_back()`][DoubleEndedIterator::next_back].
+    head: usize,
+}
+
+impl<K, V, const N: usize> IntoIter<K, V, N> {
+    /// The pairs which are not yielded yet.
+    #[inline]
+    pub(super) fn as_slice(&self) -> &[MaybeUninit<(K, V)>] {
+        &self.map.pairs[self.head..self.map.len]
+    }
 }
 
 /// Utility function for implementing Debug trait for iterators (whose inner is a slice).
@@ -162,7 +172,7 @@ impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for IterMut<'_, K, V> {
 
 impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for IntoIter<K, V, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.map.iter()).finish()
+        f.debug_list().entries(slice_iter(self.as_slice())).finish()
     }
 }
 
@@ -187,6 +197,7 @@ impl<K, V, const N: usize> Default for IntoIter<K, V, N> {
     fn default() -> Self {
         Self {
             map: Map::default(),
+            head: 0,
         }
     }
 }
@@ -240,7 +251,7 @@ impl<K, V, const N: usize> Iterator for IntoIter<K, V, N> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.map.len > 0 {
+        if self.map.len > self.head {
             self.map.len -= 1;
             Some(unsafe { self.map.item_read(self.map.len) })
         } else {
@@ -250,16 +261,62 @@ impl<K, V, const N: usize> Iterator for IntoIter<K, V, N> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.map.len, Some(self.map.len))
+        let len = self.len();
+        (len, Some(len))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.map.len()
+        self.len()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| {
+            let p = unsafe { p.assume_init_ref() };
+            (&p.0, &p.1)
+        })
     }
 }
 
-impl<'a, K, V, const N: usize> IntoIterator for &'a Map<K, V, N> {
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| {
+            let p = unsafe { p.assume_init_mut() };
+            (&p.0, &mut p.1)
+        })
+    }
+}
+
+/// Since [`next()`][Iterator::next] takes the pairs from the end of the
+/// array, this one takes them from its beginning.
+impl<K, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.head < self.map.len {
+            self.head += 1;
+            Some(unsafe { self.map.item_read(self.head - 1) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, V, const N: usize> Drop for IntoIter<K, V, N> {
+    fn drop(&mut self) {
+        let len = self.map.len;
+        // the map must not drop the pairs already taken from the front
+        self.map.len = 0;
+        for i in self.head..len {
+            unsafe { self.map.item_drop(i) };
+        }
+    }
+}
+
+impl<'a, K, V, const N: usize, E> IntoIterator for &'a Map<K, V, N, E> {
     type Item = (&'a K, &'a V);
     type IntoIter = Iter<'a, K, V>;
 
@@ -269,7 +326,7 @@ impl<'a, K, V, const N: usize> IntoIterator for &'a Map<K, V, N> {
     }
 }
 
-impl<'a, K, V, const N: usize> IntoIterator for &'a mut Map<K, V, N> {
+impl<'a, K, V, const N: usize, E> IntoIterator for &'a mut Map<K, V, N, E> {
     type Item = (&'a K, &'a mut V);
     type IntoIter = IterMut<'a, K, V>;
 
@@ -279,13 +336,16 @@ impl<'a, K, V, const N: usize> IntoIterator for &'a mut Map<K, V, N> {
     }
 }
 
-impl<K, V, const N: usize> IntoIterator for Map<K, V, N> {
+impl<K, V, const N: usize, E> IntoIterator for Map<K, V, N, E> {
     type Item = (K, V);
     type IntoIter = IntoIter<K, V, N>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter { map: self }
+        IntoIter {
+            map: self.into_parts().0,
+            head: 0,
+        }
     }
 }
 
@@ -306,7 +366,7 @@ impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {
 impl<K, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
     #[inline]
     fn len(&self) -> usize {
-        self.map.len
+        self.map.len - self.head
     }
 }
 
@@ -538,4 +598,55 @@ mod tests {
         let it_owned = m.into_iter();
         assert_eq!(it_owned.count(), 3); // Count all elements
     }
+
+    #[test]
+    fn iterate_backwards() {
+        let mut m: Map<char, u32, 4> = Map::from_iter([('a', 1), ('b', 2), ('c', 3)]);
+        let keys: Vec<_> = m.iter().rev().map(|(k, _)| *k).collect();
+        assert_eq!(keys, ['c', 'b', 'a']);
+        let mut it = m.iter();
+        assert_eq!(it.next_back(), Some((&'c', &3)));
+        assert_eq!(it.next(), Some((&'a', &1)));
+        assert_eq!(it.len(), 1);
+        if let Some((_, v)) = m.iter_mut().next_back() {
+            *v = 30;
+        }
+        assert_eq!(m.iter_mut().rfind(|(_, v)| **v < 3), Some((&'b', &mut 2)));
+        assert_eq!(m[&'c'], 30);
+    }
+
+    #[test]
+    fn into_iter_from_both_ends() {
+        let m: Map<char, u32, 4> = Map::from_iter([('a', 1), ('b', 2), ('c', 3), ('d', 4)]);
+        let forward: Vec<_> = m.clone().into_iter().collect();
+        let mut backward: Vec<_> = m.clone().into_iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        let mut it = m.into_iter();
+        let first = it.next().unwrap();
+        let last = it.next_back().unwrap();
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.size_hint(), (2, Some(2)));
+        assert_eq!(format!("{it:?}"), "[('b', 2), ('c', 3)]");
+        let rest: Vec<_> = it.collect();
+        assert_eq!(rest.len(), 2);
+        assert!(!rest.contains(&first) && !rest.contains(&last));
+    }
+
+    #[test]
+    fn into_iter_drop_from_both_ends() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 8> = Map::new();
+        for i in 0..8 {
+            m.insert(i, Rc::clone(&v));
+        }
+        let mut it = m.into_iter();
+        let taken = [it.next_back(), it.next(), it.next_back()];
+        assert_eq!(Rc::strong_count(&v), 9);
+        drop(it);
+        assert_eq!(Rc::strong_count(&v), 4); // v & the taken ones
+        drop(taken);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
 }
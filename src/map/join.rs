@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::iterators::Iter;
+use super::Map;
+use crate::{DefaultEq, KeyEq};
+use core::iter::FusedIterator;
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Visits the keys which exist in both `self` and `other`, with their
+    /// values in both maps. The iterator element type is
+    /// `(&'a K, &'a V, &'a V2)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let a = Map::from([(1, "one"), (2, "two")]);
+    /// let b = Map::from([(2, 'b'), (3, 'c')]);
+    /// let joined: Vec<_> = a.inner_join(&b).collect();
+    /// assert_eq!(joined, [(&2, &"two", &'b')]);
+    /// ```
+    ///
+    /// # Performance
+    /// Iterating over the whole join takes O(len * M) time.
+    #[inline]
+    pub fn inner_join<'a, V2, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V2, M, E>,
+    ) -> InnerJoin<'a, K, V, V2, M, E> {
+        InnerJoin {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Visits all the keys of `self` with their values, and their values in
+    /// `other` if any. The iterator element type is
+    /// `(&'a K, &'a V, Option<&'a V2>)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let a = Map::from([(1, "one"), (2, "two")]);
+    /// let b = Map::from([(2, 'b'), (3, 'c')]);
+    /// let mut joined: Vec<_> = a.left_join(&b).collect();
+    /// joined.sort_unstable();
+    /// assert_eq!(joined, [(&1, &"one", None), (&2, &"two", Some(&'b'))]);
+    /// ```
+    ///
+    /// # Performance
+    /// Iterating over the whole join takes O(len * M) time.
+    #[inline]
+    pub fn left_join<'a, V2, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V2, M, E>,
+    ) -> LeftJoin<'a, K, V, V2, M, E> {
+        LeftJoin {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Visits all the keys of both `self` and `other`, with their values in
+    /// the maps they exist in. The iterator element type is
+    /// `(&'a K, Option<&'a V>, Option<&'a V2>)`, where at least one of the
+    /// values is [`Some`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let a = Map::from([(1, "one"), (2, "two")]);
+    /// let b = Map::from([(2, 'b'), (3, 'c')]);
+    /// let mut joined: Vec<_> = a.outer_join(&b).collect();
+    /// joined.sort_unstable();
+    /// assert_eq!(
+    ///     joined,
+    ///     [
+    ///         (&1, Some(&"one"), None),
+    ///         (&2, Some(&"two"), Some(&'b')),
+    ///         (&3, None, Some(&'c')),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// # Performance
+    /// Iterating over the whole join takes O((len + M) * (N + M)) time.
+    #[inline]
+    pub fn outer_join<'a, V2, const M: usize>(
+        &'a self,
+        other: &'a Map<K, V2, M, E>,
+    ) -> OuterJoin<'a, K, V, V2, N, M, E> {
+        OuterJoin {
+            left: self.left_join(other),
+            right: other.iter(),
+            this: self,
+        }
+    }
+}
+
+/// A lazy iterator over the keys which exist in two `Map`s.
+///
+/// This `struct` is created by the [`inner_join`][Map::inner_join] method on
+/// [`Map`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InnerJoin<'a, K, V, V2, const M: usize, E = DefaultEq> {
+    iter: Iter<'a, K, V>,
+    other: &'a Map<K, V2, M, E>,
+}
+
+impl<K, V, V2, const M: usize, E> Clone for InnerJoin<'_, K, V, V2, M, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            other: self.other,
+        }
+    }
+}
+
+impl<'a, K, V, V2, const M: usize, E: KeyEq<K>> Iterator for InnerJoin<'a, K, V, V2, M, E> {
+    type Item = (&'a K, &'a V, &'a V2);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        self.iter
+            .find_map(|(k, v)| other.get(k).map(|v2| (k, v, v2)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.iter.len().min(self.other.len())))
+    }
+}
+
+impl<K, V, V2, const M: usize, E: KeyEq<K>> FusedIterator for InnerJoin<'_, K, V, V2, M, E> {}
+
+/// A lazy iterator over the keys of a `Map`, with their values in another one.
+///
+/// This `struct` is created by the [`left_join`][Map::left_join] method on
+/// [`Map`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LeftJoin<'a, K, V, V2, const M: usize, E = DefaultEq> {
+    iter: Iter<'a, K, V>,
+    other: &'a Map<K, V2, M, E>,
+}
+
+impl<K, V, V2, const M: usize, E> Clone for LeftJoin<'_, K, V, V2, M, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            other: self.other,
+        }
+    }
+}
+
+impl<'a, K, V, V2, const M: usize, E: KeyEq<K>> Iterator for LeftJoin<'a, K, V, V2, M, E> {
+    type Item = (&'a K, &'a V, Option<&'a V2>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (k, v) = self.iter.next()?;
+        Some((k, v, self.other.get(k)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V, V2, const M: usize, E: KeyEq<K>> ExactSizeIterator for LeftJoin<'_, K, V, V2, M, E> {}
+
+impl<K, V, V2, const M: usize, E: KeyEq<K>> FusedIterator for LeftJoin<'_, K, V, V2, M, E> {}
+
+/// A lazy iterator over the keys of two `Map`s, with their values in each.
+///
+/// This `struct` is created by the [`outer_join`][Map::outer_join] method on
+/// [`Map`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OuterJoin<'a, K, V, V2, const N: usize, const M: usize, E = DefaultEq> {
+    left: LeftJoin<'a, K, V, V2, M, E>,
+    /// The pairs of `other`, to visit the keys absent in `this` at the end.
+    right: Iter<'a, K, V2>,
+    this: &'a Map<K, V, N, E>,
+}
+
+impl<K, V, V2, const N: usize, const M: usize, E> Clone for OuterJoin<'_, K, V, V2, N, M, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            this: self.this,
+        }
+    }
+}
+
+impl<'a, K, V, V2, const N: usize, const M: usize, E: KeyEq<K>> Iterator
+    for OuterJoin<'a, K, V, V2, N, M, E>
+{
+    type Item = (&'a K, Option<&'a V>, Option<&'a V2>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((k, v, v2)) = self.left.next() {
+            return Some((k, Some(v), v2));
+        }
+        let this = self.this;
+        self.right
+            .find(|(k, _)| !this.contains_key(*k))
+            .map(|(k, v2)| (k, None, Some(v2)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.left.len();
+        (len, Some(len + self.right.len()))
+    }
+}
+
+impl<K, V, V2, const N: usize, const M: usize, E: KeyEq<K>> FusedIterator
+    for OuterJoin<'_, K, V, V2, N, M, E>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use crate::AsciiCaseInsensitive;
+
+    #[test]
+    fn joins_maps_of_different_capacities() {
+        let a: Map<u8, char, 3> = Map::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+        let b: Map<u8, u32, 4> = Map::from([(2, 20), (3, 30), (4, 40), (5, 50)]);
+        let mut inner: Vec<_> = a.inner_join(&b).collect();
+        inner.sort_unstable();
+        assert_eq!(inner, [(&2, &'b', &20), (&3, &'c', &30)]);
+        assert_eq!(a.left_join(&b).len(), 3);
+        assert_eq!(a.left_join(&b).filter(|(_, _, v)| v.is_none()).count(), 1);
+        let mut outer = a.outer_join(&b);
+        assert_eq!(outer.size_hint(), (3, Some(7)));
+        assert_eq!(outer.clone().count(), 5);
+        assert_eq!(outer.by_ref().filter(|(_, v, _)| v.is_none()).count(), 2);
+        assert_eq!(outer.next(), None);
+    }
+
+    #[test]
+    fn joins_with_empty_map() {
+        let a: Map<u8, char, 2> = Map::from([(1, 'a'), (2, 'b')]);
+        let e: Map<u8, (), 0> = Map::new();
+        assert_eq!(a.inner_join(&e).count(), 0);
+        assert_eq!(a.left_join(&e).count(), 2);
+        assert_eq!(e.left_join(&a).count(), 0);
+        assert_eq!(e.outer_join(&a).clone().count(), 2);
+        assert_eq!(a.inner_join(&e).clone().size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn joins_with_key_eq() {
+        let mut a: Map<&str, u8, 2, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        a.insert("Host", 1);
+        a.insert("Accept", 2);
+        let mut b: Map<&str, char, 2, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        b.insert("HOST", 'h');
+        b.insert("Range", 'r');
+        assert_eq!(a.inner_join(&b).collect::<Vec<_>>(), [(&"Host", &1, &'h')]);
+        assert_eq!(a.left_join(&b).filter(|(_, _, v)| v.is_some()).count(), 1);
+        let mut outer: Vec<_> = a.outer_join(&b).map(|(k, _, _)| *k).collect();
+        outer.sort_unstable();
+        assert_eq!(outer, ["Accept", "Host", "Range"]);
+    }
+}
@@ -1,12 +1,12 @@
 // SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
 // SPDX-License-Identifier: MIT
 
-use super::iterators::{IntoIter, Iter};
+use super::iterators::{slice_iter, IntoIter, Iter};
 use super::Map;
 use core::fmt;
 use core::iter::FusedIterator;
 
-impl<K, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
     /// An iterator visiting all keys in arbitrary order. The iterator element
     /// type is `&'a K`.
     ///
@@ -107,7 +107,9 @@ impl<K: fmt::Debug, V> fmt::Debug for Keys<'_, K, V> {
 
 impl<K: fmt::Debug, V, const N: usize> fmt::Debug for IntoKeys<K, V, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.iter.map.keys()).finish()
+        f.debug_list()
+            .entries(slice_iter(self.iter.as_slice()).map(|(k, _)| k))
+            .finish()
     }
 }
 
@@ -157,6 +159,20 @@ impl<K, V, const N: usize> Iterator for IntoKeys<K, V, N> {
     }
 }
 
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| p.0)
+    }
+}
+
+impl<K, V, const N: usize> DoubleEndedIterator for IntoKeys<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<K> {
+        self.iter.next_back().map(|p| p.0)
+    }
+}
+
 impl<K, V> ExactSizeIterator for Keys<'_, K, V> {
     fn len(&self) -> usize {
         self.iter.len()
@@ -243,4 +259,14 @@ mod tests {
         let into_keys: IntoKeys<String, i32, 10> = IntoKeys::default();
         assert_eq!(into_keys.len(), 0);
     }
+
+    #[test]
+    fn keys_backwards() {
+        let m: Map<char, i32, 4> = Map::from_iter([('a', 0), ('b', 0), ('c', 0)]);
+        assert_eq!(m.keys().rev().collect::<String>(), "cba");
+        let mut keys = m.into_keys();
+        assert_eq!(keys.next_back(), Some('a'));
+        assert_eq!(format!("{keys:?}"), "['b', 'c']");
+        assert_eq!(keys.rev().collect::<String>(), "bc");
+    }
 }
@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::KeyEq;
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Returns references to the values of `J` keys at once, scanning the
+    /// array only once. Each stored key is compared only with the keys which
+    /// are not found yet, and the scan stops as soon as all of them are.
+    ///
+    /// Returns an array of length `J` with the results of each query, where
+    /// [`None`] means the key is missing. The same key may be asked for more
+    /// than once.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([("GET", 1), ("PUT", 2), ("POST", 3)]);
+    /// assert_eq!(m.get_many(&["POST", "HEAD", "GET"]), [Some(&3), None, Some(&1)]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_many<Q, const J: usize>(&self, ks: &[&Q; J]) -> [Option<&V>; J]
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        self.positions_of(ks)
+            .map(|i| i.map(|i| &unsafe { self.item_ref(i) }.1))
+    }
+
+    /// Returns `true` if all the `J` keys are in the map, scanning the array
+    /// only once, like [`get_many()`][Map::get_many] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([("GET", 1), ("PUT", 2), ("POST", 3)]);
+    /// assert!(m.contains_all(&["PUT", "GET"]));
+    /// assert!(!m.contains_all(&["PUT", "HEAD"]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_all<Q, const J: usize>(&self, ks: &[&Q; J]) -> bool
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        self.positions_of(ks).iter().all(Option::is_some)
+    }
+
+    /// Returns `true` if any of the `J` keys is in the map, scanning the
+    /// array only once and stopping at the first stored key which matches.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([("GET", 1), ("PUT", 2), ("POST", 3)]);
+    /// assert!(m.contains_any(&["HEAD", "PUT"]));
+    /// assert!(!m.contains_any(&["HEAD", "PATCH"]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_any<Q, const J: usize>(&self, ks: &[&Q; J]) -> bool
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        self.pairs[..self.len].iter().any(|p| {
+            let p = unsafe { p.assume_init_ref() };
+            ks.iter().any(|k| self.eq.key_eq(&p.0, *k))
+        })
+    }
+
+    /// Internal function to find the indexes of the pairs with the given
+    /// keys in one pass over the array. A stored key is compared with all
+    /// the keys not found yet, so equal keys get the same index.
+    pub(crate) fn positions_of<Q, const J: usize>(&self, ks: &[&Q; J]) -> [Option<usize>; J]
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        let mut found = [None; J];
+        let mut pending = J;
+        for i in 0..self.len {
+            if pending == 0 {
+                break;
+            }
+            let p = unsafe { self.item_ref(i) };
+            for (k, f) in ks.iter().zip(found.iter_mut()) {
+                if f.is_none() && self.eq.key_eq(&p.0, *k) {
+                    *f = Some(i);
+                    pending -= 1;
+                }
+            }
+        }
+        found
+    }
+
+    /// Internal function to get mutable references to the values of the
+    /// pairs at the given indexes.
+    ///
+    /// # Safety
+    /// The indexes must be less than `len` and must not overlap.
+    pub(crate) unsafe fn values_at_mut<const J: usize>(
+        &mut self,
+        positions: [Option<usize>; J],
+    ) -> [Option<&mut V>; J] {
+        let pairs = self.pairs.as_mut_ptr();
+        positions.map(|i| i.map(|i| &mut (*pairs.add(i)).assume_init_mut().1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use crate::map::testing::Counted;
+    use crate::AsciiCaseInsensitive;
+    use std::cell::Cell;
+
+    #[test]
+    fn gets_many_values() {
+        let m: Map<u8, char, 4> = Map::from_iter([(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(m.get_many(&[&3, &9, &3]), [Some(&'c'), None, Some(&'c')]);
+        assert_eq!(m.get_many::<u8, 0>(&[]), []);
+        assert!(m.contains_all(&[&1, &2, &3]));
+        assert!(m.contains_all::<u8, 0>(&[]));
+        assert!(!m.contains_all(&[&1, &4]));
+        assert!(m.contains_any(&[&4, &1]));
+        assert!(!m.contains_any(&[&4, &5]));
+        assert!(!m.contains_any::<u8, 0>(&[]));
+    }
+
+    #[test]
+    fn scans_only_once() {
+        let calls = Cell::new(0);
+        let m: Map<Counted<'_, u8>, u8, 4> = (0..4).map(|i| (Counted(i, &calls), i)).collect();
+        calls.set(0);
+        let ks = [&Counted(1, &calls), &Counted(0, &calls)];
+        assert_eq!(m.get_many(&ks), [Some(&1), Some(&0)]);
+        assert_eq!(calls.get(), 3); // stops after the second pair
+        calls.set(0);
+        assert!(!m.contains_all(&[&Counted(3, &calls), &Counted(7, &calls)]));
+        assert_eq!(calls.get(), 8); // the second key is never found
+    }
+
+    #[test]
+    fn gets_many_with_key_eq() {
+        let mut m: Map<&str, u8, 2, _> = Map::with_key_eq(AsciiCaseInsensitive);
+        m.insert("Host", 1);
+        m.insert("Accept", 2);
+        assert_eq!(m.get_many(&["accept", "HOST"]), [Some(&2), Some(&1)]);
+        let [a, b] = m.get_disjoint_mut(["ACCEPT", "host"]);
+        std::mem::swap(a.unwrap(), b.unwrap());
+        assert_eq!(m["host"], 2);
+    }
+}
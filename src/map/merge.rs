@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::KeyEq;
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Moves all the pairs of `other` into `self`. If a key exists in both
+    /// maps, the two values are resolved into one by `f(key, self_value,
+    /// other_value)`.
+    ///
+    /// # Errors
+    /// If `self` gets full, the pairs which could not be inserted are returned
+    /// in [`Err`], while all the others are merged.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut a: Map<_, _, 4> = Map::from_iter([("x", 1), ("y", 2)]);
+    /// let b = Map::from([("y", 10), ("z", 20)]);
+    /// assert!(a.merge_with(b, |_, a, b| a + b).is_ok());
+    /// assert_eq!(a, Map::from([("x", 1), ("y", 12), ("z", 20)]));
+    /// ```
+    ///
+    /// # Performance
+    /// It takes O(len * M) time.
+    pub fn merge_with<const M: usize, F>(
+        &mut self,
+        other: Map<K, V, M, E>,
+        mut f: F,
+    ) -> Result<(), Map<K, V, M, E>>
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        let (other, eq) = other.into_parts();
+        let mut rest = Map::with_key_eq(eq);
+        for (k, b) in other {
+            if let Some(i) = self.position_of(&k) {
+                // take the pair out, so a panic in `f` can not leave a hole
+                let (k, a) = unsafe { self.remove_index_read(i) };
+                let v = f(&k, a, b);
+                self.push_back(k, v);
+            } else if self.len < N {
+                self.push_back(k, b);
+            } else {
+                // the keys of `other` are distinct, so `rest` never overflows
+                rest.push_back(k, b);
+            }
+        }
+        if rest.is_empty() {
+            Ok(())
+        } else {
+            Err(rest)
+        }
+    }
+
+    /// Retains only the keys which exist in `other` as well, resolving the
+    /// two values of each of them into one by `f(key, self_value,
+    /// other_value)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut a = Map::from([("x", 1), ("y", 2)]);
+    /// let b = Map::from([("y", 10), ("z", 20)]);
+    /// a.intersect_with(b, |_, a, b| a * b);
+    /// assert_eq!(a, Map::from([("y", 20)]));
+    /// ```
+    ///
+    /// # Performance
+    /// It takes O(len * M) time.
+    pub fn intersect_with<const M: usize, F>(&mut self, other: Map<K, V, M, E>, mut f: F)
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        self.retain(|k, _| other.contains_key(k));
+        for (k, b) in other {
+            if let Some(i) = self.position_of(&k) {
+                let (k, a) = unsafe { self.remove_index_read(i) };
+                let v = f(&k, a, b);
+                self.push_back(k, v);
+            }
+        }
+    }
+
+    /// Removes all the keys of `other` from `self`, whatever values they have
+    /// in both maps.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut a = Map::from([("x", 1), ("y", 2)]);
+    /// let b = Map::from([("y", 'b'), ("z", 'c')]);
+    /// a.difference_keys(&b);
+    /// assert_eq!(a, Map::from([("x", 1)]));
+    /// ```
+    #[inline]
+    pub fn difference_keys<V2, const M: usize>(&mut self, other: &Map<K, V2, M, E>) {
+        self.retain(|k, _| !other.contains_key(k));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+
+    #[test]
+    fn merges_maps_of_different_capacities() {
+        let mut a: Map<u8, u32, 5> = Map::from_iter([(1, 1), (2, 2), (3, 3)]);
+        let b: Map<u8, u32, 3> = Map::from([(3, 30), (4, 40), (5, 50)]);
+        assert!(a.merge_with(b, |k, a, b| u32::from(*k) + a + b).is_ok());
+        assert_eq!(a, Map::from([(1, 1), (2, 2), (3, 36), (4, 40), (5, 50)]));
+    }
+
+    #[test]
+    fn merge_returns_the_rest() {
+        let mut a = Map::from([(1, 'a'), (2, 'b')]);
+        let rest = a
+            .merge_with(Map::from([(2, 'x'), (3, 'y'), (4, 'z')]), |_, _, b| b)
+            .unwrap_err();
+        assert_eq!(a, Map::from([(1, 'a'), (2, 'x')]));
+        assert_eq!(rest, Map::from([(3, 'y'), (4, 'z')]));
+    }
+
+    #[test]
+    fn intersects_and_subtracts() {
+        let mut a = Map::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+        a.intersect_with(Map::from([(2, 20), (4, 40), (6, 60)]), |_, a, b| a.max(b));
+        assert_eq!(a, Map::from([(2, 20), (4, 40)]));
+        a.difference_keys(&Map::from([(4, ())]));
+        assert_eq!(a, Map::from([(2, 20)]));
+        a.difference_keys::<(), 0>(&Map::new());
+        assert_eq!(a.len(), 1);
+    }
+
+    #[test]
+    fn merge_with_panic_keeps_map_valid() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut a: Map<u8, Rc<()>, 2> = Map::from_iter([(1, Rc::clone(&v))]);
+        let b = Map::from([(1, Rc::clone(&v))]);
+        let r = catch_unwind(AssertUnwindSafe(|| {
+            let _ = a.merge_with(b, |_, _, _| panic!("conflict"));
+        }));
+        assert!(r.is_err());
+        assert!(a.is_empty());
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -3,9 +3,9 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
-use core::borrow::Borrow;
+use crate::KeyEq;
 
-impl<K, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
     /// Returns the number of key-value pairs the [Map] can hold,
     /// which always equal to `N`.
     ///
@@ -112,12 +112,13 @@ impl<K, V, const N: usize> Map<K, V, N> {
     }
 }
 
-impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
     /// Returns `true` if the map contains a value for the specified key.
     ///
-    /// The key may be any borrowed form of the map’s key type, but
-    /// [`PartialEq`] on the borrowed form must match those for the key
-    /// type.
+    /// The key may be any borrowed form of the map’s key type, or any other
+    /// type implementing [`Equivalent`][crate::Equivalent] for it, but the
+    /// equivalence must match [`PartialEq`] on the key type. With another
+    /// [`KeyEq`] strategy, the key may be any query type it accepts.
     ///
     /// # Examples
     /// ```
@@ -131,18 +132,19 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[must_use]
     pub fn contains_key<Q>(&self, k: &Q) -> bool
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
-        self.iter().any(|(x, _)| x.borrow() == k)
+        self.iter().any(|(x, _)| self.eq.key_eq(x, k))
     }
 
     /// Removes a key from the map, returning the value at the key if the key
     /// was previously in the map.
     ///
-    /// The key may be any borrowed form of the map’s key type, but
-    /// [`PartialEq`] on the borrowed form must match those for the key
-    /// type.
+    /// The key may be any borrowed form of the map’s key type, or any other
+    /// type implementing [`Equivalent`][crate::Equivalent] for it, but the
+    /// equivalence must match [`PartialEq`] on the key type. With another
+    /// [`KeyEq`] strategy, the key may be any query type it accepts.
     ///
     /// # Examples
     /// ```
@@ -155,13 +157,13 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[inline]
     pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
         let (i, _) = self.pairs[..self.len]
             .iter()
             .enumerate()
-            .find(|(_, p)| unsafe { p.assume_init_ref() }.0.borrow() == k)?;
+            .find(|(_, p)| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, k))?;
         Some(unsafe { self.remove_index_read(i).1 })
     }
 
@@ -309,9 +311,10 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
 
     /// Returns a reference to the value corresponding to the key.
     ///
-    /// The key may be any borrowed form of the map’s key type, but
-    /// [`PartialEq`] on the borrowed form must match those for the key
-    /// type.
+    /// The key may be any borrowed form of the map’s key type, or any other
+    /// type implementing [`Equivalent`][crate::Equivalent] for it, but the
+    /// equivalence must match [`PartialEq`] on the key type. With another
+    /// [`KeyEq`] strategy, the key may be any query type it accepts.
     ///
     /// # Examples
     /// ```
@@ -325,20 +328,21 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[must_use]
     pub fn get<Q>(&self, k: &Q) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
         let pair = self.pairs[..self.len]
             .iter()
-            .find(|p| unsafe { p.assume_init_ref() }.0.borrow() == k)?;
+            .find(|p| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, k))?;
         Some(unsafe { &pair.assume_init_ref().1 })
     }
 
     /// Returns a mutable reference to the value corresponding to the key.
     ///
-    /// The key may be any borrowed form of the map’s key type, but
-    /// [`PartialEq`] on the borrowed form must match those for the key
-    /// type.
+    /// The key may be any borrowed form of the map’s key type, or any other
+    /// type implementing [`Equivalent`][crate::Equivalent] for it, but the
+    /// equivalence must match [`PartialEq`] on the key type. With another
+    /// [`KeyEq`] strategy, the key may be any query type it accepts.
     ///
     /// # Examples
     /// ```
@@ -354,20 +358,21 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[must_use]
     pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
         let pair = self.pairs[..self.len]
             .iter_mut()
-            .find(|p| unsafe { p.assume_init_ref() }.0.borrow() == k)?;
+            .find(|p| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, k))?;
         Some(unsafe { &mut pair.assume_init_mut().1 })
     }
 
     /// Returns the key-value pair corresponding to the supplied key.
     ///
-    /// The key may be any borrowed form of the map’s key type, but
-    /// [`PartialEq`] on the borrowed form must match those for the key
-    /// type.
+    /// The key may be any borrowed form of the map’s key type, or any other
+    /// type implementing [`Equivalent`][crate::Equivalent] for it, but the
+    /// equivalence must match [`PartialEq`] on the key type. With another
+    /// [`KeyEq`] strategy, the key may be any query type it accepts.
     ///
     /// # Examples
     /// ```
@@ -399,12 +404,12 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[must_use]
     pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
         let pair = self.pairs[..self.len]
             .iter()
-            .find(|p| unsafe { p.assume_init_ref() }.0.borrow() == k)?;
+            .find(|p| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, k))?;
         let (k, v) = unsafe { pair.assume_init_ref() };
         Some((k, v))
     }
@@ -418,7 +423,8 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     /// None will be used if the key is missing.
     ///
     /// # Panics
-    /// Panics if any keys are overlapping.
+    /// Panics if two keys match the same pair in the map. The same missing
+    /// key may be given more than once, as it matches no pair.
     ///
     /// # Examples
     /// ```
@@ -475,19 +481,18 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[must_use]
     pub fn get_disjoint_mut<Q, const J: usize>(&mut self, ks: [&Q; J]) -> [Option<&mut V>; J]
     where
-        K: Borrow<Q>,
-        Q: Eq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
-        if ks.is_empty() {
-            return [const { None }; J];
-        }
-        // check for overlapping keys (O(n^2), but n is small)
-        for (i, k) in ks[..ks.len() - 1].iter().enumerate() {
-            for k_behind in &ks[i + 1..] {
-                assert!(k != k_behind, "Overlapping keys");
+        // find the keys in one pass, then check that no two of them found
+        // the same pair (O(J^2), but J is small)
+        let found = self.positions_of(&ks);
+        for (i, p) in found.iter().enumerate() {
+            for p_behind in &found[i + 1..] {
+                assert!(p.is_none() || p != p_behind, "Overlapping keys");
             }
         }
-        unsafe { self.get_disjoint_unchecked_mut(ks) }
+        unsafe { self.values_at_mut(found) }
     }
 
     /// Attempts to get mutable references to `J` values in the map at once, without validating that
@@ -500,8 +505,8 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     /// For a safe alternative see [`Map::get_disjoint_mut`].
     ///
     /// # Safety
-    /// Calling this method with overlapping keys is *[undefined behavior]* even if the resulting
-    /// references are not used.
+    /// Calling this method with two keys matching the same pair in the map is
+    /// *[undefined behavior]* even if the resulting references are not used.
     ///
     /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     ///
@@ -547,45 +552,21 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
         ks: [&Q; J],
     ) -> [Option<&mut V>; J]
     where
-        K: Borrow<Q>,
-        Q: Eq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
-        let mut ret: [Option<&mut V>; J] = [const { None }; J];
-        if ks.is_empty() {
-            return ret;
-        } else if ks.len() == 1 {
-            ret[0] = self.get_mut(ks[0]);
-            return ret;
-        }
-        // find the keys' index in one iteration of the map, store the result
-        // into the stack.
-        let mut stack = [const { None }; J];
-        let mut stack_top = 0;
-        for pair_i in 0..self.len {
-            let p = unsafe { self.item_ref(pair_i) };
-            if let Some(ks_i) = ks.iter().position(|&k| k.borrow() == p.0.borrow()) {
-                stack[stack_top] = Some((pair_i, ks_i));
-                stack_top += 1;
-            }
-        }
-        stack[..stack_top].sort_unstable_by_key(|x| x.map(|(pair_i, _)| pair_i));
-        // start splitting the found pairs from the back to the front
-        let mut rest_head = &mut self.pairs[..self.len];
-        for (pair_i, ks_i) in stack[..stack_top].iter().rev().flatten() {
-            let (head, tail) = rest_head.split_at_mut(*pair_i);
-            rest_head = head;
-            let p = unsafe { tail[0].assume_init_mut() };
-            ret[*ks_i] = Some(&mut p.1);
-        }
-        ret
+        // find the keys' indexes in one pass over the map
+        let found = self.positions_of(&ks);
+        self.values_at_mut(found)
     }
 
     /// Removes a key from the map, returning the stored key and value if
     /// the key was previously in the map.
     ///
-    /// The key may be any borrowed form of the map’s key type, but
-    /// [`PartialEq`] on the borrowed form must match those for the key
-    /// type.
+    /// The key may be any borrowed form of the map’s key type, or any other
+    /// type implementing [`Equivalent`][crate::Equivalent] for it, but the
+    /// equivalence must match [`PartialEq`] on the key type. With another
+    /// [`KeyEq`] strategy, the key may be any query type it accepts.
     ///
     /// # Examples
     /// ```
@@ -599,22 +580,24 @@ impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
     #[must_use = "if no need the return value, use `remove()` instead."]
     pub fn remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
     {
         let (i, _) = self.pairs[..self.len]
             .iter()
             .enumerate()
-            .find(|(_, p)| unsafe { p.assume_init_ref() }.0.borrow() == k)?;
+            .find(|(_, p)| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, k))?;
         Some(unsafe { self.remove_index_read(i) })
     }
 }
 
 mod internal {
     use super::Map;
+    use crate::{DefaultEq, KeyEq};
+    use core::{mem::ManuallyDrop, ptr};
 
     /// The unsafe wrapper operations for the `&[MaybeUninit]` array in [`Map`] struct.
-    impl<K, V, const N: usize> Map<K, V, N> {
+    impl<K, V, const N: usize, E> Map<K, V, N, E> {
         /// Internal function to get mutable access via reference to the value in the internal array.
         #[inline]
         #[must_use]
@@ -655,6 +638,27 @@ mod internal {
             self.pairs.get_unchecked_mut(i).write(val);
         }
 
+        /// Internal function to get the initialized pairs as a mutable slice.
+        #[inline]
+        pub(crate) fn pairs_mut(&mut self) -> &mut [(K, V)] {
+            // SAFETY: the first `len` pairs are initialized, and `MaybeUninit`
+            // has the same layout as the value it holds.
+            unsafe { core::slice::from_raw_parts_mut(self.pairs.as_mut_ptr().cast(), self.len) }
+        }
+
+        /// Append a pair to the end without checking whether the key exists already.
+        ///
+        /// # Panics
+        /// If the map is full already.
+        #[inline]
+        pub(crate) fn push_back(&mut self, k: K, v: V) -> usize {
+            let i = self.len;
+            assert!(i < N, "No more key-value slot available in the map");
+            self.pairs[i].write((k, v));
+            self.len += 1;
+            i
+        }
+
         /// Remove by index and drop it (by swapping the last one here and reducing the length).
         #[inline]
         pub(crate) unsafe fn remove_index_drop(&mut self, i: usize) {
@@ -678,10 +682,26 @@ mod internal {
             }
             result
         }
+
+        /// Internal function to split the map into the pairs, moved into a
+        /// map with the default key equality strategy, and the strategy.
+        #[inline]
+        pub(crate) fn into_parts(self) -> (Map<K, V, N>, E) {
+            let this = ManuallyDrop::new(self);
+            // SAFETY: the fields are read exactly once, and `this` is never
+            // dropped, so they are moved, not copied.
+            let (pairs, eq) = unsafe { (ptr::read(&this.pairs), ptr::read(&this.eq)) };
+            let map = Map {
+                len: this.len,
+                pairs,
+                eq: DefaultEq,
+            };
+            (map, eq)
+        }
     }
 
     /// The insert core logic for the [`Map`] struct.
-    impl<K: PartialEq, V, const N: usize> Map<K, V, N> {
+    impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
         /// The core insert logic, which is used for `insert_unchecked()`, as it will
         /// disable the bound check (`debug_assert!`) in `release` mode.
         pub(crate) fn insert_i(&mut self, k: K, v: V, update_key: bool) -> (usize, Option<(K, V)>) {
@@ -694,7 +714,7 @@ mod internal {
                     break;
                 }
                 let p = unsafe { self.item_ref(i) };
-                if p.0 == k {
+                if self.eq.key_eq(&p.0, &k) {
                     target = i;
                     existing_pair = Some(unsafe { self.item_read(i) });
                     break;
@@ -727,7 +747,7 @@ mod internal {
                 .iter_mut()
                 .map(|p| unsafe { p.assume_init_mut() })
                 .enumerate()
-                .find(|(_i, p)| p.0 == k)
+                .find(|(_i, p)| self.eq.key_eq(&p.0, &k))
             {
                 if update_key {
                     (i, Some(core::mem::replace(pair, (k, v))))
@@ -756,7 +776,7 @@ mod internal {
                 .iter_mut()
                 .map(|p| unsafe { p.assume_init_mut() })
                 .enumerate()
-                .find(|(_, p)| p.0 == k)
+                .find(|(_, p)| self.eq.key_eq(&p.0, &k))
             {
                 let existing_pair = if update_key {
                     core::mem::replace(pair, (k, v))
@@ -1222,6 +1242,15 @@ mod tests {
         assert_eq!(v2, Some(&mut 20));
     }
 
+    #[test]
+    fn get_disjoint_mut_repeated_missing_keys() {
+        let mut map: Map<&str, i32, 5> = Map::new();
+        map.insert("key2", 20);
+        let [v1, v2] = map.get_disjoint_mut(["key1", "key1"]);
+        assert_eq!(v1, None);
+        assert_eq!(v2, None);
+    }
+
     #[test]
     fn get_disjoint_unchecked_mut_missing_keys() {
         let mut map: Map<&str, i32, 5> = Map::new();
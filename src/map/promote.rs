@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use crate::KeyEq;
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Returns a mutable reference to the value corresponding to the key, and
+    /// moves the found pair one slot towards the front of the internal array
+    /// (the so-called _transpose_ heuristic).
+    ///
+    /// Since every lookup scans the array from the front, keys that are hit
+    /// frequently will gradually drift to the head of the array, and later
+    /// lookups of them will finish sooner. This is useful for skewed access
+    /// patterns, where a few keys get most of the hits.
+    ///
+    /// Note that the iteration order of the map will change after a hit.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<_, _, 3> = Map::new();
+    /// m.insert(1, "a");
+    /// m.insert(2, "b");
+    /// m.insert(3, "c");
+    /// assert_eq!(m.get_promote(&3), Some(&mut "c"));
+    /// assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 3, 2]);
+    /// assert_eq!(m.get_promote(&3), Some(&mut "c"));
+    /// assert_eq!(m.keys().copied().collect::<Vec<_>>(), [3, 1, 2]);
+    /// assert_eq!(m.get_promote(&4), None);
+    /// ```
+    #[inline]
+    pub fn get_promote<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        let i = self.position_of(k)?;
+        let i = self.transpose_front(i);
+        Some(unsafe { self.value_mut(i) })
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key, and
+    /// moves the found pair to the very front of the internal array (the
+    /// so-called _move-to-front_ heuristic).
+    ///
+    /// It adapts to a changed access pattern faster than
+    /// [`get_promote()`][Self::get_promote], but each hit shifts all the pairs
+    /// in front of the found one by one slot. The relative order of the other
+    /// pairs is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m: Map<_, _, 3> = Map::new();
+    /// m.insert(1, "a");
+    /// m.insert(2, "b");
+    /// m.insert(3, "c");
+    /// assert_eq!(m.get_promote_to_front(&3), Some(&mut "c"));
+    /// assert_eq!(m.keys().copied().collect::<Vec<_>>(), [3, 1, 2]);
+    /// assert_eq!(m.get_promote_to_front(&4), None);
+    /// ```
+    #[inline]
+    pub fn get_promote_to_front<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        let i = self.position_of(k)?;
+        self.move_to_front(i);
+        Some(unsafe { self.value_mut(0) })
+    }
+}
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Internal function to find the index of the pair with the given key.
+    #[inline]
+    pub(crate) fn position_of<Q>(&self, k: &Q) -> Option<usize>
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        self.pairs[..self.len]
+            .iter()
+            .position(|p| self.eq.key_eq(&unsafe { p.assume_init_ref() }.0, k))
+    }
+
+    /// Swap the pair at index `i` with its predecessor (if any), returning
+    /// its new index.
+    #[inline]
+    pub(crate) fn transpose_front(&mut self, i: usize) -> usize {
+        if i == 0 {
+            return 0;
+        }
+        self.pairs.swap(i - 1, i);
+        i - 1
+    }
+
+    /// Move the pair at index `i` to the front, shifting the pairs before it
+    /// back by one slot.
+    #[inline]
+    pub(crate) fn move_to_front(&mut self, i: usize) {
+        self.pairs[..=i].rotate_right(1);
+    }
+
+    /// Move the pair at index `i` to the back, shifting the pairs after it
+    /// forward by one slot.
+    #[inline]
+    pub(crate) fn move_to_back(&mut self, i: usize) {
+        self.pairs[i..self.len].rotate_left(1);
+    }
+
+    /// Insert a new pair at the front, shifting all the pairs back by one slot.
+    /// The caller must make sure that the key is not in the map yet.
+    ///
+    /// # Panics
+    /// If the map is full already.
+    #[inline]
+    pub(crate) fn push_front(&mut self, k: K, v: V) {
+        let i = self.push_back(k, v);
+        self.move_to_front(i);
+    }
+
+    /// Remove the last pair of the internal array and return it.
+    #[inline]
+    pub(crate) fn pop_back(&mut self) -> Option<(K, V)> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.item_read(self.len) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+
+    #[test]
+    fn get_promote_moves_one_step() {
+        let mut m: Map<i32, i32, 4> = Map::from_iter([(1, 10), (2, 20), (3, 30), (4, 40)]);
+        assert_eq!(m.get_promote(&4), Some(&mut 40));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 4, 3]);
+        assert_eq!(m.get_promote(&1), Some(&mut 10));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 4, 3]);
+        *m.get_promote(&4).unwrap() += 1;
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 4, 2, 3]);
+        assert_eq!(m[&4], 41);
+        assert_eq!(m.get_promote(&5), None);
+        assert_eq!(m.len(), 4);
+    }
+
+    #[test]
+    fn get_promote_to_front_keeps_relative_order() {
+        let mut m: Map<i32, i32, 5> = Map::from_iter([(1, 10), (2, 20), (3, 30), (4, 40)]);
+        assert_eq!(m.get_promote_to_front(&3), Some(&mut 30));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [3, 1, 2, 4]);
+        assert_eq!(m.get_promote_to_front(&3), Some(&mut 30));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [3, 1, 2, 4]);
+        assert_eq!(m.get_promote_to_front(&4), Some(&mut 40));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [4, 3, 1, 2]);
+        assert_eq!(m.get_promote_to_front(&5), None);
+        m.insert(5, 50);
+        assert_eq!(m.remove(&4), Some(40));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [5, 3, 1, 2]);
+    }
+
+    #[test]
+    fn promote_with_borrowed_keys() {
+        let mut m: Map<String, i32, 3> = Map::new();
+        m.insert("a".to_string(), 1);
+        m.insert("b".to_string(), 2);
+        assert_eq!(m.get_promote("b"), Some(&mut 2));
+        assert_eq!(m.get_promote_to_front("a"), Some(&mut 1));
+        assert_eq!(m.get_promote("c"), None);
+    }
+
+    #[test]
+    fn promote_does_not_drop_values() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: Map<i32, Rc<()>, 3> = Map::new();
+        for i in 0..3 {
+            m.insert(i, Rc::clone(&v));
+        }
+        let _ = m.get_promote(&2);
+        let _ = m.get_promote_to_front(&1);
+        assert_eq!(Rc::strong_count(&v), 4);
+        drop(m);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::entry::OccupiedEntry;
+use super::Map;
+use crate::{DefaultEq, KeyEq};
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Creates a raw entry builder for the map, which looks up an entry with
+    /// a closure instead of a whole key.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// use micromap::map::RawEntryMut;
+    /// let mut m: Map<(u32, &str), u32, 4> = Map::new();
+    /// for name in ["bob", "eve", "bob"] {
+    ///     match m.raw_entry_mut().search(|k, _| k.1 == name) {
+    ///         RawEntryMut::Occupied(mut o) => *o.get_mut() += 1,
+    ///         RawEntryMut::Vacant(v) => {
+    ///             v.insert((7, name), 1);
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(m[&(7, "bob")], 2);
+    /// ```
+    #[inline]
+    pub fn raw_entry_mut(&mut self) -> RawEntryBuilderMut<'_, K, V, N, E> {
+        RawEntryBuilderMut { table: self }
+    }
+}
+
+/// A builder for computing where in a `Map` a pair would be stored.
+///
+/// This `struct` is created by the [`raw_entry_mut`][Map::raw_entry_mut]
+/// method on [`Map`]. See its documentation for more.
+pub struct RawEntryBuilderMut<'a, K, V, const N: usize, E = DefaultEq> {
+    table: &'a mut Map<K, V, N, E>,
+}
+
+impl<'a, K, V, const N: usize, E> RawEntryBuilderMut<'a, K, V, N, E> {
+    /// Looks up the first pair matching the predicate.
+    #[inline]
+    pub fn search<F: FnMut(&K, &V) -> bool>(self, f: F) -> RawEntryMut<'a, K, V, N, E> {
+        let index = self.table.position(f);
+        self.at(index)
+    }
+
+    /// Looks up the pair with the given key, like [`Map::entry_ref`] does.
+    #[inline]
+    pub fn from_key<Q>(self, k: &Q) -> RawEntryMut<'a, K, V, N, E>
+    where
+        E: KeyEq<K, Q>,
+        Q: ?Sized,
+    {
+        let index = self.table.position_of(k);
+        self.at(index)
+    }
+
+    /// Internal function to make the entry at the found index, if any.
+    #[inline]
+    fn at(self, index: Option<usize>) -> RawEntryMut<'a, K, V, N, E> {
+        match index {
+            Some(index) => RawEntryMut::Occupied(OccupiedEntry {
+                index,
+                table: self.table,
+            }),
+            None => RawEntryMut::Vacant(RawVacantEntryMut { table: self.table }),
+        }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or
+/// occupied, found by a closure.
+///
+/// This `enum` is constructed from the [`RawEntryBuilderMut`].
+pub enum RawEntryMut<'a, K, V, const N: usize, E = DefaultEq> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N, E>),
+    /// A vacant entry.
+    Vacant(RawVacantEntryMut<'a, K, V, N, E>),
+}
+
+/// A view into a vacant entry in a `Map`, which does not know its key yet.
+/// It is part of the [`RawEntryMut`] enum.
+pub struct RawVacantEntryMut<'a, K, V, const N: usize, E = DefaultEq> {
+    table: &'a mut Map<K, V, N, E>,
+}
+
+impl<'a, K, V, const N: usize, E: KeyEq<K>> RawEntryMut<'a, K, V, N, E> {
+    /// Ensures a value is in the entry by inserting the given pair if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the map is full already.
+    #[inline]
+    pub fn or_insert(self, default_key: K, default_val: V) -> &'a mut V {
+        self.or_insert_with(|| (default_key, default_val))
+    }
+
+    /// Ensures a value is in the entry by inserting the pair made by the
+    /// default function if empty, and returns a mutable reference to the
+    /// value in the entry.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the map is full already.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> (K, V)>(self, default: F) -> &'a mut V {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_mut(),
+            RawEntryMut::Vacant(entry) => {
+                let (k, v) = default();
+                entry.insert(k, v)
+            }
+        }
+    }
+}
+
+impl<'a, K, V, const N: usize, E: KeyEq<K>> RawVacantEntryMut<'a, K, V, N, E> {
+    /// Inserts the pair into the map, and returns a mutable reference to the
+    /// value.
+    ///
+    /// The key must not be in the map already, since the closure used to
+    /// search may not match it.
+    ///
+    /// # Panics
+    /// If the map is full already, or if the key is in the map already.
+    #[inline]
+    pub fn insert(self, k: K, v: V) -> &'a mut V {
+        assert!(
+            self.table.position_of(&k).is_none(),
+            "The key exists in the map"
+        );
+        let i = self.table.push_back(k, v);
+        unsafe { self.table.value_mut(i) }
+    }
+
+    /// Attempts to insert the pair into the map, and returns a mutable
+    /// reference to the value. The key must not be in the map already.
+    ///
+    /// # Errors
+    /// If the map is full already, the pair is returned back in [`Err`].
+    ///
+    /// # Panics
+    /// If the key is in the map already.
+    #[inline]
+    pub fn try_insert(self, k: K, v: V) -> Result<&'a mut V, (K, V)> {
+        if self.table.len == N {
+            return Err((k, v));
+        }
+        Ok(self.insert(k, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, RawEntryMut};
+
+    #[test]
+    fn search_by_closure() {
+        let mut m: Map<(u8, char), u32, 2> = Map::new();
+        *m.raw_entry_mut()
+            .search(|k, _| k.1 == 'a')
+            .or_insert((1, 'a'), 0) += 5;
+        *m.raw_entry_mut()
+            .search(|k, _| k.1 == 'a')
+            .or_insert_with(|| unreachable!()) += 5;
+        assert_eq!(m[&(1, 'a')], 10);
+        let RawEntryMut::Occupied(o) = m.raw_entry_mut().from_key(&(1, 'a')) else {
+            unreachable!("the key is in the map");
+        };
+        assert_eq!(o.remove_entry(), ((1, 'a'), 10));
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn try_insert_into_full_map() {
+        let mut m: Map<u8, u8, 1> = Map::from([(1, 1)]);
+        let RawEntryMut::Vacant(v) = m.raw_entry_mut().search(|_, v| *v == 2) else {
+            unreachable!("no value is 2");
+        };
+        assert_eq!(v.try_insert(2, 2), Err((2, 2)));
+        let mut e: Map<u8, u8, 1> = Map::new();
+        let RawEntryMut::Vacant(v) = e.raw_entry_mut().from_key(&3) else {
+            unreachable!("the map is empty");
+        };
+        assert_eq!(v.try_insert(3, 3), Ok(&mut 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "The key exists in the map")]
+    fn insert_existing_key_panics() {
+        let mut m: Map<u8, u8, 2> = Map::from_iter([(1, 1)]);
+        if let RawEntryMut::Vacant(v) = m.raw_entry_mut().search(|_, _| false) {
+            v.insert(1, 2);
+        }
+    }
+}
@@ -2,13 +2,14 @@
 // SPDX-License-Identifier: MIT
 
 use super::Map;
+use crate::KeyEq;
 use core::fmt::Formatter;
 use core::marker::PhantomData;
 use serde::de::{MapAccess, Visitor};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-impl<K: Serialize, V: Serialize, const N: usize> Serialize for Map<K, V, N> {
+impl<K: Serialize, V: Serialize, const N: usize, E> Serialize for Map<K, V, N, E> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -21,12 +22,15 @@ impl<K: Serialize, V: Serialize, const N: usize> Serialize for Map<K, V, N> {
     }
 }
 
-struct Vi<K, V, const N: usize>(PhantomData<K>, PhantomData<V>);
+struct Vi<K, V, const N: usize, E>(PhantomData<K>, PhantomData<V>, PhantomData<E>);
 
-impl<'de, K: PartialEq + Deserialize<'de>, V: Deserialize<'de>, const N: usize> Visitor<'de>
-    for Vi<K, V, N>
+impl<'de, K, V, const N: usize, E> Visitor<'de> for Vi<K, V, N, E>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+    E: KeyEq<K> + Default,
 {
-    type Value = Map<K, V, N>;
+    type Value = Map<K, V, N, E>;
 
     fn expecting(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
         formatter.write_str("a Map")
@@ -36,7 +40,7 @@ impl<'de, K: PartialEq + Deserialize<'de>, V: Deserialize<'de>, const N: usize>
     where
         M: MapAccess<'de>,
     {
-        let mut m: Self::Value = Map::new();
+        let mut m: Self::Value = Map::default();
         while let Some((key, value)) = access.next_entry()? {
             m.insert(key, value);
         }
@@ -44,14 +48,17 @@ impl<'de, K: PartialEq + Deserialize<'de>, V: Deserialize<'de>, const N: usize>
     }
 }
 
-impl<'de, K: PartialEq + Deserialize<'de>, V: Deserialize<'de>, const N: usize> Deserialize<'de>
-    for Map<K, V, N>
+impl<'de, K, V, const N: usize, E> Deserialize<'de> for Map<K, V, N, E>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+    E: KeyEq<K> + Default,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(Vi(PhantomData, PhantomData))
+        deserializer.deserialize_map(Vi(PhantomData, PhantomData, PhantomData))
     }
 }
 
@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::iterators::IterMut;
+use super::values::ValuesMut;
+use super::Map;
+use crate::DefaultEq;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::Deref;
+use core::{fmt, ptr};
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Returns the pairs as a slice, in the order of [`iter()`][Map::iter].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([(1, 'a'), (2, 'b')]);
+    /// assert_eq!(m.as_slice(), [(1, 'a'), (2, 'b')]);
+    /// ```
+    #[inline]
+    pub const fn as_slice(&self) -> &[(K, V)] {
+        // SAFETY: the first `len` pairs are initialized, and `MaybeUninit`
+        // has the same layout as the value it holds.
+        unsafe { core::slice::from_raw_parts(self.pairs.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the pairs as a mutable [`Slice`], which gives mutable access
+    /// to the values and keeps the keys read-only, so they stay unique.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([(1, 10), (2, 20)]);
+    /// let s = m.as_mut_slice();
+    /// s.values_mut().for_each(|v| *v += 1);
+    /// *s.get_index_mut(0).unwrap().1 = 0;
+    /// s.swap_indices(0, 1);
+    /// assert_eq!(m.as_slice(), [(2, 21), (1, 0)]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut Slice<K, V> {
+        Slice::from_mut(self.pairs_mut())
+    }
+
+    /// Consumes the map and returns its internal array together with the
+    /// number of pairs in it. The first `len` items of the array are
+    /// initialized, the others are not.
+    ///
+    /// Nothing is dropped, the pairs now belong to the caller. The array
+    /// can be turned back into a map by
+    /// [`from_array_unchecked()`][Map::from_array_unchecked].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m: Map<_, _, 4> = Map::from_iter([(1, "a"), (2, "b")]);
+    /// let (pairs, len) = m.into_array();
+    /// assert_eq!(len, 2);
+    /// assert_eq!(unsafe { pairs[1].assume_init_read() }, (2, "b"));
+    /// ```
+    #[inline]
+    pub fn into_array(self) -> ([MaybeUninit<(K, V)>; N], usize) {
+        let (map, _) = self.into_parts();
+        let map = ManuallyDrop::new(map);
+        // SAFETY: `map` is never dropped, so the pairs are moved, not copied.
+        (unsafe { ptr::read(&map.pairs) }, map.len)
+    }
+}
+
+impl<K, V, const N: usize> Map<K, V, N> {
+    /// Makes a map from an array, whose first `len` items are the pairs,
+    /// without any checks. This is the reverse of
+    /// [`into_array()`][Map::into_array].
+    ///
+    /// # Safety
+    /// The first `len` items of `pairs` must be initialized, their keys must
+    /// be unique, and `len` must not be more than `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m: Map<_, _, 4> = Map::from_iter([(1, "a"), (2, "b")]);
+    /// let (pairs, len) = m.into_array();
+    /// let m = unsafe { Map::from_array_unchecked(pairs, len) };
+    /// assert_eq!(m[&2], "b");
+    /// ```
+    #[inline]
+    pub const unsafe fn from_array_unchecked(pairs: [MaybeUninit<(K, V)>; N], len: usize) -> Self {
+        debug_assert!(len <= N, "The length is more than the capacity");
+        Self {
+            len,
+            pairs,
+            eq: DefaultEq,
+        }
+    }
+}
+
+/// A mutable view of the pairs of a [`Map`], in the order of its
+/// [`iter()`][Map::iter].
+///
+/// It is created by [`Map::as_mut_slice()`]. It can be read as a plain
+/// `[(K, V)]` slice, while only the values and the order of the pairs may
+/// be changed through it, so the keys stay unique.
+///
+/// # Example
+/// ```
+/// use micromap::Map;
+/// let mut m = Map::from([("a", 1), ("b", 2)]);
+/// let s = m.as_mut_slice();
+/// assert_eq!(s.len(), 2);
+/// assert_eq!(s[1], ("b", 2));
+/// ```
+#[repr(transparent)]
+pub struct Slice<K, V> {
+    pairs: [(K, V)],
+}
+
+impl<K, V> Slice<K, V> {
+    #[inline]
+    fn from_mut(pairs: &mut [(K, V)]) -> &mut Self {
+        // SAFETY: `Slice` is a transparent wrapper around the slice.
+        unsafe { &mut *(ptr::from_mut(pairs) as *mut Self) }
+    }
+
+    /// Returns the key and the mutable value of the pair at the index, or
+    /// [`None`] if it is out of bounds.
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.pairs.get_mut(index).map(|(k, v)| (&*k, v))
+    }
+
+    /// Swaps the pairs at the two indexes.
+    ///
+    /// # Panics
+    /// If `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.pairs.swap(a, b);
+    }
+
+    /// Reverses the order of the pairs.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.pairs.reverse();
+    }
+
+    /// An iterator visiting all the pairs, with mutable references to the
+    /// values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let pairs = ptr::from_mut(&mut self.pairs) as *mut [MaybeUninit<(K, V)>];
+        IterMut {
+            // SAFETY: all the pairs are initialized, and the iterator never
+            // writes uninitialized memory into them.
+            iter: unsafe { &mut *pairs }.iter_mut(),
+        }
+    }
+
+    /// An iterator visiting all the values mutably.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+}
+
+impl<K, V> Deref for Slice<K, V> {
+    type Target = [(K, V)];
+
+    #[inline]
+    fn deref(&self) -> &[(K, V)] {
+        &self.pairs
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut Slice<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Slice<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.pairs).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use std::rc::Rc;
+
+    #[test]
+    fn views_pairs_as_slices() {
+        let mut m: Map<char, u8, 4> = Map::from_iter([('a', 1), ('b', 2), ('c', 3)]);
+        m.remove(&'a');
+        assert_eq!(m.as_slice(), [('c', 3), ('b', 2)]);
+        let s = m.as_mut_slice();
+        s.reverse();
+        if let Some((k, v)) = s.get_index_mut(1) {
+            *v = u8::try_from(*k).unwrap();
+        }
+        assert!(s.get_index_mut(2).is_none());
+        assert_eq!(format!("{s:?}"), "[('b', 2), ('c', 99)]");
+        assert_eq!(m.iter().collect::<Vec<_>>(), [(&'b', &2), (&'c', &99)]);
+        assert!(Map::<u8, u8, 0>::new().as_slice().is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_array() {
+        let v = Rc::new(());
+        let mut m: Map<u8, Rc<()>, 3> = Map::new();
+        m.insert(1, Rc::clone(&v));
+        m.insert(2, Rc::clone(&v));
+        let (pairs, len) = m.into_array();
+        assert_eq!(len, 2);
+        assert_eq!(Rc::strong_count(&v), 3);
+        let mut m = unsafe { Map::from_array_unchecked(pairs, len) };
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2]);
+        m.insert(3, Rc::clone(&v));
+        drop(m);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use core::cmp::Ordering;
+use core::mem::MaybeUninit;
+
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
+    /// Sorts the pairs in place by their keys.
+    ///
+    /// The keys are unique, so the order is fully determined by them. The
+    /// sorted order is seen by [`iter()`][Map::iter] and the others, until
+    /// a removal swaps the last pair into the gap.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+    /// m.sort_keys();
+    /// assert_eq!(m.to_string(), "{1: a, 2: b, 3: c}");
+    /// ```
+    #[inline]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.pairs_mut().sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    /// Sorts the pairs in place with a comparator function.
+    ///
+    /// This sort is stable: the pairs which are equal for the comparator
+    /// keep their order. It is an insertion sort, which needs no memory
+    /// and is fast enough for the small maps this crate is made for.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([("a", 2), ("b", 1), ("c", 2)]);
+    /// m.sort_by(|(_, v1), (_, v2)| v2.cmp(v1));
+    /// assert_eq!(m.keys().copied().collect::<Vec<_>>(), ["a", "c", "b"]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut((&K, &V), (&K, &V)) -> Ordering,
+    {
+        let pairs = self.pairs_mut();
+        for i in 1..pairs.len() {
+            let mut j = i;
+            while j > 0 {
+                let (a, b) = (&pairs[j - 1], &pairs[j]);
+                if compare((&a.0, &a.1), (&b.0, &b.1)) != Ordering::Greater {
+                    break;
+                }
+                pairs.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Sorts the pairs in place with a key extraction function.
+    ///
+    /// This sort is unstable: the pairs with equal sort keys may be
+    /// reordered.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([("x", -3_i32), ("y", 1), ("z", -2)]);
+    /// m.sort_unstable_by_key(|_, v| v.abs());
+    /// assert_eq!(m.values().copied().collect::<Vec<_>>(), [1, -2, -3]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.pairs_mut().sort_unstable_by_key(|(k, v)| f(k, v));
+    }
+
+    /// Sorts the pairs in place with a key extraction function, which is
+    /// called only once per pair.
+    ///
+    /// This is worth it when the sort keys are expensive to compute. They
+    /// are kept on the stack, in an array of capacity `N`. This sort is
+    /// stable: the pairs with equal sort keys keep their order.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut m = Map::from([(1, "ccc"), (2, "a"), (3, "bb")]);
+    /// m.sort_by_cached_key(|_, v| v.to_string());
+    /// assert_eq!(m.keys().copied().collect::<Vec<_>>(), [2, 3, 1]);
+    /// ```
+    pub fn sort_by_cached_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        let pairs = self.pairs_mut();
+        let mut cache: [MaybeUninit<(T, usize)>; N] = [const { MaybeUninit::uninit() }; N];
+        for (i, (k, v)) in pairs.iter().enumerate() {
+            cache[i].write((f(k, v), i));
+        }
+        // SAFETY: the first `len` items are initialized right above. If a
+        // comparison panics, the sort keys are leaked, but never read again.
+        let indices: &mut [(T, usize)] =
+            unsafe { core::slice::from_raw_parts_mut(cache.as_mut_ptr().cast(), pairs.len()) };
+        // the positions are unique, so the unstable sort is stable here
+        indices.sort_unstable();
+        // the same permutation as in `slice::sort_by_cached_key()` of `std`
+        for i in 0..pairs.len() {
+            let mut index = indices[i].1;
+            while index < i {
+                index = indices[index].1;
+            }
+            indices[i].1 = index;
+            pairs.swap(i, index);
+        }
+        for item in indices {
+            // SAFETY: each sort key is initialized and dropped only once
+            unsafe { core::ptr::drop_in_place(item) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use std::cell::Cell;
+
+    #[test]
+    fn sorts_keys() {
+        let mut m: Map<u8, char, 8> = Map::new();
+        for (k, v) in [(5, 'e'), (2, 'b'), (7, 'g'), (1, 'a')] {
+            m.insert(k, v);
+        }
+        m.sort_keys();
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 5, 7]);
+        assert_eq!(m[&5], 'e');
+        m.remove(&1);
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [7, 2, 5]);
+    }
+
+    #[test]
+    fn sorts_stable_by_comparator() {
+        let mut m: Map<u8, u8, 6> = (0..6).map(|k| (k, k % 3)).collect();
+        m.sort_by(|(_, a), (_, b)| a.cmp(b));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 3, 1, 4, 2, 5]);
+        m.sort_by(|(a, _), (b, _)| b.cmp(a));
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn sorts_unstable_by_key() {
+        let mut m: Map<&str, u32, 3> = Map::from([("ccc", 0), ("a", 1), ("bb", 2)]);
+        m.sort_unstable_by_key(|k, _| k.len());
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), ["a", "bb", "ccc"]);
+    }
+
+    #[test]
+    fn computes_cached_keys_once() {
+        let calls = Cell::new(0);
+        let mut m: Map<u8, String, 8> = (0..8).map(|k| (k, (k % 4).to_string())).collect();
+        m.sort_by_cached_key(|_, v| {
+            calls.set(calls.get() + 1);
+            v.clone()
+        });
+        assert_eq!(calls.get(), 8);
+        assert_eq!(
+            m.keys().copied().collect::<Vec<_>>(),
+            [0, 4, 1, 5, 2, 6, 3, 7]
+        );
+        let mut e: Map<u8, u8, 0> = Map::new();
+        e.sort_by_cached_key(|k, _| *k);
+        assert!(e.is_empty());
+    }
+}
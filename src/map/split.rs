@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Map;
+use core::ptr;
+
+impl<K, V, const N: usize, E: Clone> Map<K, V, N, E> {
+    /// Splits the map into two by the predicate: the pairs for which it
+    /// returns `true` go to the first map, the others go to the second one.
+    ///
+    /// The pairs are moved without any comparison of the keys, and their
+    /// order is kept in both maps.
+    ///
+    /// # Panics
+    /// If one of the two maps can not hold all its pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let m = Map::from([("x", 1), ("y", 2), ("z", 3)]);
+    /// let (odd, even): (Map<_, _, 3>, Map<_, _, 1>) = m.partition(|_, v| v % 2 == 1);
+    /// assert_eq!(odd, Map::from([("x", 1), ("z", 3)]));
+    /// assert_eq!(even, Map::from([("y", 2)]));
+    /// ```
+    pub fn partition<const A: usize, const B: usize, F>(
+        self,
+        mut pred: F,
+    ) -> (Map<K, V, A, E>, Map<K, V, B, E>)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let (mut from, eq) = self.into_parts();
+        let mut left = Map::with_key_eq(eq.clone());
+        let mut right = Map::with_key_eq(eq);
+        for (k, v) in from.drain() {
+            if pred(&k, &v) {
+                left.push_back(k, v);
+            } else {
+                right.push_back(k, v);
+            }
+        }
+        (left, right)
+    }
+
+    /// Splits the map into two at the given position of the internal array.
+    /// Returns a map with the pairs at `[at, len)`, and `self` keeps the
+    /// pairs at `[0, at)`.
+    ///
+    /// The positions are the ones in which [`iter()`][Map::iter] visits the
+    /// pairs.
+    ///
+    /// # Panics
+    /// If `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut a = Map::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// let b = a.split_off(1);
+    /// assert_eq!(a, Map::from([(1, 'a')]));
+    /// assert_eq!(b, Map::from([(2, 'b'), (3, 'c')]));
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "The split position is out of bounds");
+        let mut other = Self::with_key_eq(self.eq.clone());
+        // SAFETY: the pairs at `[at, len)` are initialized and are forgotten
+        // by `self` right after, so they are moved, not copied.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.pairs[at..].as_ptr(),
+                other.pairs.as_mut_ptr(),
+                self.len - at,
+            );
+        }
+        other.len = self.len - at;
+        self.len = at;
+        other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use std::rc::Rc;
+
+    #[test]
+    fn partitions_by_predicate() {
+        let m: Map<u8, u8, 6> = (0..6).map(|x| (x, x * 10)).collect();
+        let (small, big): (Map<u8, u8, 4>, Map<u8, u8, 4>) = m.partition(|k, _| *k < 2);
+        assert_eq!(small.keys().copied().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(big.keys().copied().collect::<Vec<_>>(), [2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn partition_overflow_panics() {
+        let m: Map<u8, u8, 3> = Map::from([(1, 1), (2, 2), (3, 3)]);
+        let _: (Map<u8, u8, 1>, Map<u8, u8, 3>) = m.partition(|_, _| true);
+    }
+
+    #[test]
+    fn splits_off_at_bounds() {
+        let mut a: Map<u8, u8, 3> = Map::from([(1, 1), (2, 2), (3, 3)]);
+        let b = a.split_off(3);
+        assert!(b.is_empty());
+        assert_eq!(a.len(), 3);
+        let b = a.split_off(0);
+        assert!(a.is_empty());
+        assert_eq!(b.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "The split position is out of bounds")]
+    fn split_off_out_of_bounds() {
+        let mut a: Map<u8, u8, 3> = Map::from([(1, 1), (2, 2), (3, 3)]);
+        a.remove(&1);
+        let _ = a.split_off(3);
+    }
+
+    #[test]
+    fn split_off_moves_pairs() {
+        let v = Rc::new(());
+        let mut a: Map<u8, Rc<()>, 4> = Map::new();
+        for i in 0..4 {
+            a.insert(i, Rc::clone(&v));
+        }
+        let b = a.split_off(1);
+        assert_eq!(Rc::strong_count(&v), 5);
+        drop(a);
+        assert_eq!(Rc::strong_count(&v), 4);
+        drop(b);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! Helpers shared by the tests of the map.
+
+use std::cell::Cell;
+
+/// A key which counts how many times it is compared for equality.
+#[derive(Debug)]
+pub struct Counted<'a, T>(pub T, pub &'a Cell<usize>);
+
+impl<T: PartialEq> PartialEq for Counted<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1.set(self.1.get() + 1);
+        self.0 == other.0
+    }
+}
@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::entry::{Entry, OccupiedEntry, VacantEntry};
+use super::Map;
+use crate::{DefaultEq, KeyEq};
+
+impl<K, V, const N: usize, E: KeyEq<K>> Map<K, V, N, E> {
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation, like [`entry()`][Map::entry], unless the key is absent
+    /// and there is no room for it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// use micromap::map::TryEntry;
+    /// let mut map: Map<&str, u32, 1> = Map::new();
+    /// if let TryEntry::Vacant(v) = map.try_entry("a") {
+    ///     v.insert(1);
+    /// }
+    /// assert!(matches!(map.try_entry("a"), TryEntry::Occupied(_)));
+    /// assert!(matches!(map.try_entry("b"), TryEntry::Full("b")));
+    /// ```
+    #[inline]
+    pub fn try_entry(&mut self, k: K) -> TryEntry<'_, K, V, N, E> {
+        match self.position_of(&k) {
+            Some(index) => TryEntry::Occupied(OccupiedEntry { index, table: self }),
+            None if self.len == N => TryEntry::Full(k),
+            None => TryEntry::Vacant(VacantEntry {
+                key: k,
+                table: self,
+            }),
+        }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant, occupied,
+/// or impossible to insert because the map is full.
+///
+/// This `enum` is constructed from the [`try_entry`][Map::try_entry] method
+/// on [`Map`].
+pub enum TryEntry<'a, K, V, const N: usize, E = DefaultEq> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N, E>),
+    /// A vacant entry, which can be inserted into without panic.
+    Vacant(VacantEntry<'a, K, V, N, E>),
+    /// The key is absent and the map is full, so the key is given back.
+    Full(K),
+}
+
+impl<'a, K, V, const N: usize, E> TryEntry<'a, K, V, N, E> {
+    /// Returns a reference to this entry's key.
+    #[inline]
+    #[must_use]
+    pub fn key(&self) -> &K {
+        match self {
+            TryEntry::Occupied(entry) => entry.key(),
+            TryEntry::Vacant(entry) => entry.key(),
+            TryEntry::Full(k) => k,
+        }
+    }
+
+    /// Converts it into an [`Entry`], or returns the key back if the map
+    /// is full.
+    ///
+    /// # Errors
+    /// If the key is absent and the map is full, the key is returned in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Map;
+    /// let mut map: Map<&str, u32, 1> = Map::new();
+    /// *map.try_entry("a").into_entry().unwrap().or_insert(0) += 1;
+    /// assert_eq!(map["a"], 1);
+    /// assert_eq!(map.try_entry("b").into_entry().err(), Some("b"));
+    /// ```
+    #[inline]
+    pub fn into_entry(self) -> Result<Entry<'a, K, V, N, E>, K> {
+        match self {
+            TryEntry::Occupied(entry) => Ok(Entry::Occupied(entry)),
+            TryEntry::Vacant(entry) => Ok(Entry::Vacant(entry)),
+            TryEntry::Full(k) => Err(k),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, TryEntry};
+    use crate::map::Entry;
+
+    #[test]
+    fn never_panics_on_full_map() {
+        let mut m: Map<u8, u8, 2> = Map::new();
+        for k in 0..4 {
+            match m.try_entry(k) {
+                TryEntry::Occupied(_) => unreachable!("all keys are new"),
+                TryEntry::Vacant(v) => {
+                    v.insert(k);
+                }
+                TryEntry::Full(k) => assert!(k >= 2),
+            }
+        }
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.try_entry(1).key(), &1);
+        assert_eq!(m.try_entry(9).key(), &9);
+        assert_eq!(m.entry(1).or_try_insert_with(|| 5), Ok(&mut 1));
+        assert_eq!(m.entry(7).or_try_insert_with(|| 7), Err((7, 7)));
+        let Entry::Vacant(v) = m.entry(8) else {
+            unreachable!("8 is not in the map");
+        };
+        assert_eq!(v.try_insert(8), Err((8, 8)));
+    }
+
+    #[test]
+    fn vacant_entry_of_try_entry_fits() {
+        let mut m: Map<u8, u8, 1> = Map::new();
+        let TryEntry::Vacant(v) = m.try_entry(1) else {
+            unreachable!("the map is empty");
+        };
+        assert_eq!(v.try_insert(1), Ok(&mut 1));
+    }
+}
@@ -6,7 +6,7 @@ use super::iterators::{IntoIter, Iter, IterMut};
 use super::Map;
 use core::{fmt, iter::FusedIterator};
 
-impl<K, V, const N: usize> Map<K, V, N> {
+impl<K, V, const N: usize, E> Map<K, V, N, E> {
     /// An iterator visiting all values in arbitrary order. The iterator element
     /// type is `&'a V`.
     ///
@@ -109,7 +109,7 @@ pub struct Values<'a, K, V> {
 #[repr(transparent)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct ValuesMut<'a, K, V> {
-    iter: IterMut<'a, K, V>,
+    pub(super) iter: IterMut<'a, K, V>,
 }
 
 /// An owning iterator over the values of a `Map`.
@@ -186,7 +186,9 @@ impl<K, V: fmt::Debug> fmt::Debug for ValuesMut<'_, K, V> {
 
 impl<K, V: fmt::Debug, const N: usize> fmt::Debug for IntoValues<K, V, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.iter.map.values()).finish()
+        f.debug_list()
+            .entries(slice_iter(self.iter.as_slice()).map(|(_, v)| v))
+            .finish()
     }
 }
 
@@ -232,6 +234,27 @@ impl<K, V, const N: usize> Iterator for IntoValues<K, V, N> {
     }
 }
 
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| p.1)
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ValuesMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|p| p.1)
+    }
+}
+
+impl<K, V, const N: usize> DoubleEndedIterator for IntoValues<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<V> {
+        self.iter.next_back().map(|p| p.1)
+    }
+}
+
 impl<K, V> ExactSizeIterator for Values<'_, K, V> {
     #[inline]
     fn len(&self) -> usize {
@@ -378,4 +401,20 @@ mod tests {
         assert!(debug_str.contains("42"));
         assert!(debug_str.contains("16"));
     }
+
+    #[test]
+    fn values_backwards() {
+        let mut m: Map<char, i32, 4> = Map::from_iter([('a', 1), ('b', 2), ('c', 3)]);
+        assert_eq!(m.values().rev().collect::<Vec<_>>(), [&3, &2, &1]);
+        assert_eq!(m.values().rfind(|v| **v < 3), Some(&2));
+        if let Some(v) = m.values_mut().next_back() {
+            *v = 30;
+        }
+        let mut values = m.into_values();
+        assert_eq!(values.next_back(), Some(1));
+        assert_eq!(format!("{values:?}"), "[2, 30]");
+        assert_eq!(values.next_back(), Some(2));
+        assert_eq!(values.next(), Some(30));
+        assert!(values.next_back().is_none());
+    }
 }
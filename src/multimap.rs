@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! A small Map allowing several values per key, based on a fixed length array.
+
+mod entry;
+mod iterators;
+mod methods;
+
+// re-export
+pub use entry::Entry;
+pub use iterators::{GetAll, Keys};
+
+use crate::map::Map;
+use core::fmt;
+
+/// A fixed-capacity map, which can hold several values for the same key.
+///
+/// It stores up to `N` key-value pairs in one array, in insertion order, and
+/// the same key may appear in many pairs. This is handy for headers, tags and
+/// other lists with repeated keys, without reserving a nested collection for
+/// every key.
+///
+/// ```
+/// use micromap::MultiMap;
+/// let mut m: MultiMap<&str, u32, 8> = MultiMap::new();
+/// m.insert("tag", 1);
+/// m.insert("id", 7);
+/// m.insert("tag", 2);
+/// assert_eq!(m.len(), 3);
+/// assert_eq!(m.get_all("tag").collect::<Vec<_>>(), [&1, &2]);
+/// assert_eq!(m.keys().collect::<Vec<_>>(), [&"tag", &"id"]);
+/// ```
+///
+/// The removal keeps the insertion order of the rest pairs, so it takes O(len)
+/// time, like the lookup itself.
+pub struct MultiMap<K, V, const N: usize> {
+    map: Map<K, V, N>,
+}
+
+impl<K, V, const N: usize> Default for MultiMap<K, V, N> {
+    /// Creates an empty [`MultiMap`] like [`new()`][`MultiMap::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for MultiMap<K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for MultiMap<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a MultiMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = crate::map::Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> FromIterator<(K, V)> for MultiMap<K, V, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut m = Self::new();
+        for (k, v) in iter {
+            m.insert(k, v);
+        }
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiMap;
+
+    #[test]
+    fn various() {
+        let mut m: MultiMap<char, u8, 5> = MultiMap::default();
+        assert!(m.is_empty());
+        assert_eq!(m.capacity(), 5);
+        m.insert('a', 1);
+        m.insert('b', 2);
+        m.insert('a', 3);
+        assert_eq!(m.len(), 3);
+        assert_eq!(format!("{m:?}"), "{'a': 1, 'b': 2, 'a': 3}");
+        let cloned = m.clone();
+        assert_eq!((&cloned).into_iter().count(), 3);
+        m.clear();
+        assert!(m.is_empty());
+        let m: MultiMap<char, u8, 5> = cloned.iter().map(|(k, v)| (*k, *v + 1)).collect();
+        assert_eq!(m.get_all(&'a').copied().collect::<Vec<_>>(), [2, 4]);
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::MultiMap;
+
+impl<K: PartialEq, V, const N: usize> MultiMap<K, V, N> {
+    /// Gets the given key's entry in the map, to append values to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<&str, u32, 4> = MultiMap::new();
+    /// m.entry("a").append(1);
+    /// *m.entry("a").append(2) += 10;
+    /// *m.entry("b").or_insert(3) += 1;
+    /// *m.entry("a").or_insert(4) += 1;
+    /// assert_eq!(m.get_all("a").collect::<Vec<_>>(), [&2, &12]);
+    /// assert_eq!(m.get("b"), Some(&4));
+    /// ```
+    #[inline]
+    pub fn entry(&mut self, k: K) -> Entry<'_, K, V, N> {
+        Entry {
+            key: k,
+            table: self,
+        }
+    }
+}
+
+/// A view into all the pairs of one key in a [`MultiMap`].
+///
+/// This `struct` is created by the [`entry`][MultiMap::entry] method on
+/// [`MultiMap`]. See its documentation for more.
+///
+/// [`MultiMap`]: super::MultiMap
+/// [MultiMap::entry]: super::MultiMap::entry
+pub struct Entry<'a, K, V, const N: usize> {
+    key: K,
+    table: &'a mut MultiMap<K, V, N>,
+}
+
+impl<'a, K: PartialEq, V, const N: usize> Entry<'a, K, V, N> {
+    /// Returns a reference to this entry's key.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the number of values this entry's key has in the map.
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.table.key_count(&self.key)
+    }
+
+    /// Appends a value to this entry's key, returning a mutable reference
+    /// to it.
+    ///
+    /// # Panics
+    /// If the map is full already. If you want to avoid this, use
+    /// [`try_append()`][Self::try_append] instead.
+    #[inline]
+    pub fn append(self, value: V) -> &'a mut V {
+        let i = self.table.map.push_back(self.key, value);
+        unsafe { self.table.map.value_mut(i) }
+    }
+
+    /// Attempts to append a value to this entry's key, returning a mutable
+    /// reference to it.
+    ///
+    /// # Errors
+    /// If the map is full already, nothing is changed and the key-value pair
+    /// is returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<char, u32, 1> = MultiMap::new();
+    /// assert_eq!(m.entry('a').try_append(1), Ok(&mut 1));
+    /// assert_eq!(m.entry('a').try_append(2), Err(('a', 2)));
+    /// ```
+    #[inline]
+    pub fn try_append(self, value: V) -> Result<&'a mut V, (K, V)> {
+        if self.table.len() == N {
+            return Err((self.key, value));
+        }
+        Ok(self.append(value))
+    }
+
+    /// Returns a mutable reference to the first value of this entry's key,
+    /// appending the default if the key has no value yet.
+    ///
+    /// # Panics
+    /// If the key is absent and the map is full already.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Returns a mutable reference to the first value of this entry's key,
+    /// appending the result of the default function if the key has no value
+    /// yet.
+    ///
+    /// # Panics
+    /// If the key is absent and the map is full already.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self.table.map.position_of(&self.key) {
+            Some(i) => unsafe { self.table.map.value_mut(i) },
+            None => self.append(default()),
+        }
+    }
+
+    /// Returns a mutable reference to the first value of this entry's key,
+    /// appending the default value if the key has no value yet.
+    ///
+    /// # Panics
+    /// If the key is absent and the map is full already.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MultiMap;
+
+    #[test]
+    fn appends_and_counts() {
+        let mut m: MultiMap<&str, Vec<u8>, 3> = MultiMap::new();
+        m.entry("a").or_default().push(1);
+        m.entry("a").or_default().push(2);
+        m.entry("a").append(vec![3]);
+        assert_eq!(m.entry("a").count(), 2);
+        assert_eq!(m.entry("b").key(), &"b");
+        assert_eq!(m.entry("b").count(), 0);
+        assert_eq!(m.get_all("a").collect::<Vec<_>>(), [&[1, 2][..], &[3][..]]);
+        m.entry("c").append(vec![]);
+        assert_eq!(m.entry("d").try_append(vec![4]), Err(("d", vec![4])));
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn or_insert_into_full_map_panics() {
+        let mut m: MultiMap<u8, u8, 1> = MultiMap::new();
+        m.entry(1).or_insert(1);
+        m.entry(2).or_insert(2);
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use crate::map::Iter;
+use crate::Equivalent;
+use core::iter::FusedIterator;
+
+/// An iterator over the values of one key in a [`MultiMap`].
+///
+/// This `struct` is created by the [`get_all`][MultiMap::get_all] method on
+/// [`MultiMap`]. See its documentation for more.
+///
+/// [`MultiMap`]: super::MultiMap
+/// [MultiMap::get_all]: super::MultiMap::get_all
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GetAll<'a, 'q, K, V, Q: ?Sized> {
+    pub(super) iter: Iter<'a, K, V>,
+    pub(super) key: &'q Q,
+}
+
+impl<K, V, Q: ?Sized> Clone for GetAll<'_, '_, K, V, Q> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            key: self.key,
+        }
+    }
+}
+
+impl<'a, K, V, Q> Iterator for GetAll<'a, '_, K, V, Q>
+where
+    Q: Equivalent<K> + ?Sized,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a V> {
+        let key = self.key;
+        self.iter.find(|(k, _)| key.equivalent(*k)).map(|(_, v)| v)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<K, V, Q> FusedIterator for GetAll<'_, '_, K, V, Q> where Q: Equivalent<K> + ?Sized {}
+
+/// An iterator over the distinct keys of a [`MultiMap`].
+///
+/// This `struct` is created by the [`keys`][MultiMap::keys] method on
+/// [`MultiMap`]. See its documentation for more.
+///
+/// [`MultiMap`]: super::MultiMap
+/// [MultiMap::keys]: super::MultiMap::keys
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Keys<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+    /// All the pairs, to check whether a key was yielded already.
+    pub(super) seen: Iter<'a, K, V>,
+    /// The index of the next pair in `iter`.
+    pub(super) index: usize,
+}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            seen: self.seen.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<'a, K: PartialEq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a K> {
+        loop {
+            let (k, _) = self.iter.next()?;
+            let i = self.index;
+            self.index += 1;
+            if !self.seen.clone().take(i).any(|(x, _)| x == k) {
+                return Some(k);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the rest of the pairs may all have the keys yielded already
+        (0, Some(self.iter.len()))
+    }
+}
+
+impl<K: PartialEq, V> FusedIterator for Keys<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::MultiMap;
+
+    #[test]
+    fn iterators_can_be_cloned() {
+        let m: MultiMap<u8, u8, 4> = MultiMap::from_iter([(1, 1), (2, 2), (1, 3)]);
+        let mut all = m.get_all(&1);
+        assert_eq!(all.next(), Some(&1));
+        assert_eq!(all.clone().collect::<Vec<_>>(), [&3]);
+        assert_eq!(all.size_hint(), (0, Some(2)));
+        let mut keys = m.keys();
+        assert_eq!(keys.size_hint(), (0, Some(3)));
+        assert_eq!(keys.next(), Some(&1));
+        assert_eq!(keys.clone().count(), 1);
+        assert_eq!(keys.next(), Some(&2));
+        assert_eq!(keys.next(), None);
+        assert_eq!(keys.next(), None);
+    }
+
+    #[test]
+    fn keys_size_hint_with_trailing_duplicates() {
+        let m: MultiMap<u8, u8, 4> = MultiMap::from_iter([(1, 1), (2, 2), (1, 3)]);
+        let mut keys = m.keys();
+        assert_eq!(keys.next(), Some(&1));
+        assert_eq!(keys.size_hint(), (0, Some(2)));
+        assert_eq!(keys.next(), Some(&2));
+        assert_eq!(keys.size_hint(), (0, Some(1)));
+        assert_eq!(keys.next(), None);
+        assert_eq!(keys.size_hint(), (0, Some(0)));
+    }
+}
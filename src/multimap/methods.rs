@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::iterators::{GetAll, Keys};
+use super::MultiMap;
+use crate::map::{Iter, Map};
+use crate::Equivalent;
+
+impl<K, V, const N: usize> MultiMap<K, V, N> {
+    /// Creates an empty [`MultiMap`] with capacity `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let m: MultiMap<&str, i32, 8> = MultiMap::new();
+    /// assert_eq!(m.capacity(), 8);
+    /// assert_eq!(m.len(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: Map::new() }
+    }
+
+    /// Returns the number of pairs the map can hold, which always equal to `N`.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of key-value pairs in the map (not the number of
+    /// distinct keys).
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no pair.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Clears the map, removing all pairs (drop them).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// An iterator visiting all key-value pairs in insertion order. A key
+    /// appears as many times as it has values.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    /// Retains only the pairs specified by the predicate, keeping the insertion
+    /// order of the retained ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 4> = MultiMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("a", 2);
+    /// m.insert("b", 3);
+    /// m.retain(|_, v| *v != 2);
+    /// assert_eq!(m.iter().collect::<Vec<_>>(), [(&"a", &1), (&"b", &3)]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        let mut i = 0;
+        while i < self.map.len() {
+            let p = unsafe { self.map.item_mut(i) };
+            if f(&p.0, &mut p.1) {
+                i += 1;
+            } else {
+                self.remove_index(i);
+            }
+        }
+    }
+
+    /// Internal function to remove the pair at index `i`, keeping the order
+    /// of the other pairs.
+    #[inline]
+    pub(super) fn remove_index(&mut self, i: usize) -> (K, V) {
+        self.map.move_to_back(i);
+        self.map
+            .pop_back()
+            .expect("the index of the removed pair must be valid")
+    }
+}
+
+impl<K: PartialEq, V, const N: usize> MultiMap<K, V, N> {
+    /// Appends a key-value pair to the map, even if the key exists already.
+    ///
+    /// # Panics
+    /// If the map is full already. If you want to avoid this, use
+    /// [`try_insert()`][Self::try_insert] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 2> = MultiMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("a", 2);
+    /// assert_eq!(m.key_count("a"), 2);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) {
+        self.map.push_back(k, v);
+    }
+
+    /// Attempts to append a key-value pair to the map.
+    ///
+    /// # Errors
+    /// If the map is full already, nothing is changed and the given pair is
+    /// returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 1> = MultiMap::new();
+    /// assert_eq!(m.try_insert("a", 1), Ok(()));
+    /// assert_eq!(m.try_insert("a", 2), Err(("a", 2)));
+    /// ```
+    #[inline]
+    pub fn try_insert(&mut self, k: K, v: V) -> Result<(), (K, V)> {
+        if self.map.len() == N {
+            return Err((k, v));
+        }
+        self.map.push_back(k, v);
+        Ok(())
+    }
+
+    /// Returns a reference to the first value corresponding to the key.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 3> = MultiMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("a", 2);
+    /// assert_eq!(m.get("a"), Some(&1));
+    /// assert_eq!(m.get("b"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get(k)
+    }
+
+    /// An iterator visiting all the values corresponding to the key, in
+    /// insertion order.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 3> = MultiMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("b", 2);
+    /// m.insert("a", 3);
+    /// assert_eq!(m.get_all("a").collect::<Vec<_>>(), [&1, &3]);
+    /// assert_eq!(m.get_all("c").count(), 0);
+    /// ```
+    #[inline]
+    pub fn get_all<'a, 'q, Q>(&'a self, k: &'q Q) -> GetAll<'a, 'q, K, V, Q>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        GetAll {
+            iter: self.map.iter(),
+            key: k,
+        }
+    }
+
+    /// Returns `true` if the map contains at least one value for the key.
+    #[inline]
+    #[must_use]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.contains_key(k)
+    }
+
+    /// Returns the number of values corresponding to the key.
+    #[inline]
+    #[must_use]
+    pub fn key_count<Q>(&self, k: &Q) -> usize
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.get_all(k).count()
+    }
+
+    /// An iterator visiting all distinct keys, in the order of their first
+    /// insertion.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 4> = MultiMap::new();
+    /// m.insert("b", 1);
+    /// m.insert("a", 2);
+    /// m.insert("b", 3);
+    /// assert_eq!(m.keys().collect::<Vec<_>>(), [&"b", &"a"]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            iter: self.map.iter(),
+            seen: self.map.iter(),
+            index: 0,
+        }
+    }
+
+    /// Removes the first pair with the key, returning its value.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 3> = MultiMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("a", 2);
+    /// assert_eq!(m.remove("a"), Some(1));
+    /// assert_eq!(m.remove("a"), Some(2));
+    /// assert_eq!(m.remove("a"), None);
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let i = self.map.position_of(k)?;
+        Some(self.remove_index(i).1)
+    }
+
+    /// Removes all the pairs with the key, returning the number of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::MultiMap;
+    /// let mut m: MultiMap<_, _, 3> = MultiMap::new();
+    /// m.insert("a", 1);
+    /// m.insert("b", 2);
+    /// m.insert("a", 3);
+    /// assert_eq!(m.remove_all("a"), 2);
+    /// assert_eq!(m.remove_all("a"), 0);
+    /// assert_eq!(m.len(), 1);
+    /// ```
+    #[inline]
+    pub fn remove_all<Q>(&mut self, k: &Q) -> usize
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let len = self.len();
+        self.retain(|x, _| !k.equivalent(x));
+        len - self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiMap;
+
+    #[test]
+    fn keeps_insertion_order() {
+        let mut m: MultiMap<&str, i32, 6> = MultiMap::new();
+        for (k, v) in [("a", 1), ("b", 2), ("a", 3), ("c", 4), ("a", 5)] {
+            m.insert(k, v);
+        }
+        assert_eq!(m.get_all("a").copied().collect::<Vec<_>>(), [1, 3, 5]);
+        assert_eq!(m.remove("a"), Some(1));
+        assert_eq!(m.get_all("a").copied().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(m.remove_all("a"), 2);
+        assert_eq!(m.iter().collect::<Vec<_>>(), [(&"b", &2), (&"c", &4)]);
+    }
+
+    #[test]
+    fn keys_are_distinct() {
+        let m: MultiMap<i32, i32, 8> =
+            MultiMap::from_iter([(1, 1), (2, 2), (1, 3), (3, 4), (2, 5), (1, 6)]);
+        assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(m.key_count(&1), 3);
+        assert_eq!(m.key_count(&4), 0);
+        assert!(m.contains_key(&3));
+        assert_eq!(m.get(&2), Some(&2));
+    }
+
+    #[test]
+    fn full_map() {
+        let mut m: MultiMap<i32, i32, 2> = MultiMap::new();
+        assert_eq!(m.try_insert(1, 1), Ok(()));
+        assert_eq!(m.try_insert(1, 1), Ok(()));
+        assert_eq!(m.try_insert(1, 1), Err((1, 1)));
+        assert_eq!(m.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn insert_into_full_map_panics() {
+        let mut m: MultiMap<i32, i32, 1> = MultiMap::new();
+        m.insert(1, 1);
+        m.insert(1, 2);
+    }
+
+    #[test]
+    fn remove_all_drops_values() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: MultiMap<i32, Rc<()>, 4> = MultiMap::new();
+        for i in 0..4 {
+            m.insert(i % 2, Rc::clone(&v));
+        }
+        assert_eq!(m.remove_all(&0), 2);
+        assert_eq!(Rc::strong_count(&v), 3);
+        drop(m);
+        assert_eq!(Rc::strong_count(&v), 1);
+    }
+}
@@ -3,15 +3,22 @@
 
 //! A small Set implemented as a Linear Map where the value is `()`.
 
+mod append;
+mod assign;
 mod bitand;
+mod bitor;
+mod bitxor;
+mod capacity;
 mod clone;
 mod ctors;
 mod debug;
 mod difference;
 mod display;
 mod drain;
+mod entry;
 mod eq;
 mod extend;
+mod extract_if;
 mod from;
 mod intersection;
 mod iterators;
@@ -19,15 +26,18 @@ mod methods;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod serialization;
+mod slice;
+mod sort;
+mod split;
 mod sub;
 mod symmetric_difference;
 mod union;
-// mod bitor; // need nightly Rust to enable `#![feature(generic_const_exprs)]`
-// mod bitxor; // need nightly Rust to enable `#![feature(generic_const_exprs)]`
 
 // re-export
 pub use difference::Difference;
 pub use drain::Drain;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use extract_if::ExtractIf;
 pub use intersection::Intersection;
 pub use iterators::{IntoIter, Iter};
 pub use symmetric_difference::SymmetricDifference;
@@ -140,4 +150,17 @@ mod tests {
         assert_eq!(set_a, set_b);
         assert_eq!(set_a, set_c);
     }
+
+    #[test]
+    fn iterate_backwards() {
+        let mut set: Set<char, 4> = Set::from_iter(['a', 'b', 'c']);
+        assert_eq!(set.iter().rev().collect::<String>(), "cba");
+        let mut it = set.clone().into_iter();
+        assert_eq!(it.next_back(), Some('a'));
+        assert_eq!(it.len(), 2);
+        let mut drain = set.drain();
+        assert_eq!(drain.next_back(), Some('c'));
+        assert_eq!(drain.rev().collect::<String>(), "ba");
+        assert!(set.is_empty());
+    }
 }
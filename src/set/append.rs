@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Moves all the values out of `other` into `self`, leaving `other`
+    /// empty. The values which are in `self` already are dropped.
+    ///
+    /// # Panics
+    /// If there are too many new values to fit into `self`. All the values
+    /// which fit are moved before the panic, and the others stay in `other`.
+    /// If you want to avoid this, use [`try_append()`][Set::try_append]
+    /// instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a: Set<_, 4> = Set::from_iter([1, 2]);
+    /// let mut b = Set::from([2, 3]);
+    /// a.append(&mut b);
+    /// assert_eq!(a, Set::from([1, 2, 3]));
+    /// assert!(b.is_empty());
+    /// ```
+    #[inline]
+    pub fn append<const M: usize>(&mut self, other: &mut Set<T, M>) {
+        self.map.append(&mut other.map);
+    }
+
+    /// Attempts to move all the values out of `other` into `self`, like
+    /// [`append()`][Set::append] does.
+    ///
+    /// # Errors
+    /// If `self` gets full, the new values which could not be moved stay in
+    /// `other`, and their number is returned in [`Err`]. All the others are
+    /// moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a: Set<_, 2> = Set::from_iter([1]);
+    /// let mut b = Set::from([1, 2, 3]);
+    /// assert_eq!(a.try_append(&mut b), Err(1));
+    /// assert_eq!(a, Set::from([1, 2]));
+    /// assert_eq!(b, Set::from([3]));
+    /// ```
+    #[inline]
+    pub fn try_append<const M: usize>(&mut self, other: &mut Set<T, M>) -> Result<(), usize> {
+        self.map.try_append(&mut other.map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn appends_distinct_values() {
+        let mut a: Set<char, 4> = Set::from_iter(['a', 'b']);
+        let mut b: Set<char, 3> = Set::from(['c', 'b', 'd']);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.iter().copied().collect::<String>(), "abcd");
+        let mut c = Set::from(['e']);
+        assert_eq!(a.try_append(&mut c), Err(1));
+        assert_eq!(c.len(), 1);
+    }
+}
@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use core::ops::{BitAndAssign, BitOrAssign, BitXorAssign, SubAssign};
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Retains only the values which are also in `other`, in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3, 4]);
+    /// a.retain_intersection(&Set::from([2, 4, 6]));
+    /// assert_eq!(a, Set::from([2, 4]));
+    /// ```
+    #[inline]
+    pub fn retain_intersection<const M: usize>(&mut self, other: &Set<T, M>) {
+        self.retain(|x| other.contains(x));
+    }
+
+    /// Removes all the values which are in `other`, in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3, 4]);
+    /// a.remove_all(&Set::from([2, 4, 6]));
+    /// assert_eq!(a, Set::from([1, 3]));
+    /// ```
+    #[inline]
+    pub fn remove_all<const M: usize>(&mut self, other: &Set<T, M>) {
+        self.retain(|x| !other.contains(x));
+    }
+
+    /// Moves all the values of `other` into `self`, skipping the ones which
+    /// are in `self` already.
+    ///
+    /// # Errors
+    /// If `self` gets full, the values which could not be inserted are
+    /// returned in [`Err`], while all the others are inserted.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a: Set<_, 4> = Set::from_iter([1, 2]);
+    /// assert_eq!(a.insert_all(Set::from([2, 3])), Ok(()));
+    /// assert_eq!(a, Set::from([1, 2, 3]));
+    /// let rest = a.insert_all(Set::from([4, 5, 6])).unwrap_err();
+    /// assert_eq!(a.len(), 4);
+    /// assert_eq!(rest.len(), 2);
+    /// ```
+    #[inline]
+    pub fn insert_all<const M: usize>(&mut self, other: Set<T, M>) -> Result<(), Set<T, M>> {
+        let mut rest = Set::new();
+        for x in other {
+            if self.contains(&x) {
+                continue;
+            }
+            // the values of `other` are distinct, so `rest` never overflows
+            if self.len() < N {
+                self.map.push_back(x, ());
+            } else {
+                rest.map.push_back(x, ());
+            }
+        }
+        if rest.is_empty() {
+            Ok(())
+        } else {
+            Err(rest)
+        }
+    }
+
+    /// Removes the values which are in both `self` and `other`, and moves the
+    /// rest values of `other` into `self`, i.e. the symmetric difference in
+    /// place.
+    ///
+    /// # Errors
+    /// If `self` gets full, the values which could not be inserted are
+    /// returned in [`Err`], while all the others are toggled.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3]);
+    /// assert_eq!(a.toggle_all(Set::from([2, 3, 4])), Ok(()));
+    /// assert_eq!(a, Set::from([1, 4]));
+    /// ```
+    #[inline]
+    pub fn toggle_all<const M: usize>(&mut self, mut other: Set<T, M>) -> Result<(), Set<T, M>> {
+        // remove the common values first, to free slots for the new ones
+        self.retain(|x| !other.remove(x));
+        self.insert_all(other)
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> BitAndAssign<&Set<T, M>> for Set<T, N> {
+    /// Retains the intersection of `self` and `rhs` in place, like
+    /// [`retain_intersection()`][Set::retain_intersection].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3]);
+    /// a &= &Set::from([2, 3, 4]);
+    /// assert_eq!(a, Set::from([2, 3]));
+    /// ```
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &Set<T, M>) {
+        self.retain_intersection(rhs);
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> SubAssign<&Set<T, M>> for Set<T, N> {
+    /// Retains the difference of `self` and `rhs` in place, like
+    /// [`remove_all()`][Set::remove_all].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3]);
+    /// a -= &Set::from([2, 3, 4]);
+    /// assert_eq!(a, Set::from([1]));
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Set<T, M>) {
+        self.remove_all(rhs);
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> BitOrAssign<Set<T, M>> for Set<T, N> {
+    /// Moves the union of `self` and `rhs` into `self`, like
+    /// [`insert_all()`][Set::insert_all].
+    ///
+    /// # Panics
+    /// If the union has more than `N` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a: Set<_, 4> = Set::from_iter([1, 2, 3]);
+    /// a |= Set::from([2, 3, 4]);
+    /// assert_eq!(a, Set::from([1, 2, 3, 4]));
+    /// ```
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Set<T, M>) {
+        assert!(
+            self.insert_all(rhs).is_ok(),
+            "The union has more than {N} values"
+        );
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> BitXorAssign<Set<T, M>> for Set<T, N> {
+    /// Moves the symmetric difference of `self` and `rhs` into `self`, like
+    /// [`toggle_all()`][Set::toggle_all].
+    ///
+    /// # Panics
+    /// If the symmetric difference has more than `N` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3]);
+    /// a ^= Set::from([2, 3, 4]);
+    /// assert_eq!(a, Set::from([1, 4]));
+    /// ```
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Set<T, M>) {
+        assert!(
+            self.toggle_all(rhs).is_ok(),
+            "The symmetric difference has more than {N} values"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    /// A value without `Clone`, which the non-assigning operators need.
+    #[derive(Debug, PartialEq)]
+    struct Token(u8);
+
+    #[test]
+    fn works_without_clone() {
+        let mut a: Set<Token, 4> = Set::from_iter([Token(1), Token(2), Token(3)]);
+        a &= &Set::from([Token(1), Token(2)]);
+        assert_eq!(a.len(), 2);
+        a -= &Set::from([Token(1)]);
+        assert_eq!(a, Set::from([Token(2)]));
+        a |= Set::from([Token(2), Token(3)]);
+        assert_eq!(a, Set::from([Token(2), Token(3)]));
+        a ^= Set::from([Token(3), Token(4)]);
+        assert_eq!(a, Set::from([Token(2), Token(4)]));
+    }
+
+    #[test]
+    fn toggle_frees_slots_first() {
+        let mut a = Set::from([1, 2]);
+        assert_eq!(a.toggle_all(Set::from([1, 2, 3])), Ok(()));
+        assert_eq!(a, Set::from([3]));
+        let mut b = Set::from([1, 2]);
+        let rest = b.toggle_all(Set::from([2, 3, 4])).unwrap_err();
+        assert_eq!(b.len(), 2);
+        assert!(b.contains(&1));
+        assert_eq!(rest.len(), 1);
+    }
+
+    #[test]
+    fn insert_all_returns_the_rest() {
+        let mut a = Set::from([1]);
+        let rest = a.insert_all(Set::from([1, 2, 3])).unwrap_err();
+        assert_eq!(a, Set::from([1]));
+        assert_eq!(rest, Set::from([2, 3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "The union has more than 2 values")]
+    fn bitor_assign_overflow_panics() {
+        let mut a = Set::from([1, 2]);
+        a |= Set::from([3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The symmetric difference has more than 1 values")]
+    fn bitxor_assign_overflow_panics() {
+        let mut a = Set::from([1]);
+        a ^= Set::from([2]);
+    }
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use core::ops::BitOr;
+
+// If we can use `#![feature(generic_const_exprs)]`, use `Set<T, {N + M}>` as the
+// const generic parameter to replace the `Set<T, N>`. Until then, the capacity
+// can be chosen explicitly with `Set::union_into`.
+impl<T, const N: usize, const M: usize> BitOr<&Set<T, M>> for &Set<T, N>
+where
+    T: PartialEq + Clone,
+{
+    type Output = Set<T, N>;
+
+    /// Returns the union of `self` and `rhs` as a new `Set<T, N>`.
+    /// The capacity of return set is same as `Self`.
+    ///
+    /// # Panics
+    /// If the union has more than `N` values. If you want to avoid this, use
+    /// [`union_into()`][Set::union_into] with a larger capacity instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let a: Set<_, 4> = Set::from_iter([1, 2, 3]);
+    /// let b = Set::from([2, 3, 4]);
+    /// let set = &a | &b;
+    /// assert_eq!(set, Set::from([1, 2, 3, 4]));
+    /// ```
+    #[inline]
+    fn bitor(self, rhs: &Set<T, M>) -> Set<T, N> {
+        self.union_into(rhs)
+            .unwrap_or_else(|_| panic!("The union has more than {N} values"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn bitor_with_overlapping_sets() {
+        let a: Set<_, 5> = Set::from_iter([1, 2, 3]);
+        let b = Set::from([3, 4, 5]);
+        assert_eq!(&a | &b, Set::from([1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn bitor_with_empty_sets() {
+        let a = Set::from([1, 2, 3]);
+        let b: Set<i32, 3> = Set::new();
+        assert_eq!(&a | &b, a);
+        assert_eq!(&b | &b, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "The union has more than 3 values")]
+    fn bitor_overflow_panics() {
+        let a = Set::from([1, 2, 3]);
+        let b = Set::from([4]);
+        let _ = &a | &b;
+    }
+}
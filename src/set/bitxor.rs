@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use core::ops::BitXor;
+
+// If we can use `#![feature(generic_const_exprs)]`, use `Set<T, {N + M}>` as the
+// const generic parameter to replace the `Set<T, N>`. Until then, the capacity
+// can be chosen explicitly with `Set::symmetric_difference_into`.
+impl<T, const N: usize, const M: usize> BitXor<&Set<T, M>> for &Set<T, N>
+where
+    T: PartialEq + Clone,
+{
+    type Output = Set<T, N>;
+
+    /// Returns the symmetric difference of `self` and `rhs` as a new `Set<T, N>`.
+    /// The capacity of return set is same as `Self`.
+    ///
+    /// # Panics
+    /// If the symmetric difference has more than `N` values. If you want to
+    /// avoid this, use [`symmetric_difference_into()`][Set::symmetric_difference_into]
+    /// with a larger capacity instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let a = Set::from([1, 2, 3]);
+    /// let b = Set::from([2, 3, 4]);
+    /// let set = &a ^ &b;
+    /// assert_eq!(set, Set::from([1, 4]));
+    /// ```
+    #[inline]
+    fn bitxor(self, rhs: &Set<T, M>) -> Set<T, N> {
+        self.symmetric_difference_into(rhs)
+            .unwrap_or_else(|_| panic!("The symmetric difference has more than {N} values"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn bitxor_with_overlapping_sets() {
+        let a = Set::from([1, 2, 3, 4]);
+        let b = Set::from([3, 4, 5]);
+        assert_eq!(&a ^ &b, Set::from([1, 2, 5]));
+    }
+
+    #[test]
+    fn bitxor_with_same_set() {
+        let a = Set::from([1, 2, 3]);
+        assert!((&a ^ &a).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "The symmetric difference has more than 2 values")]
+    fn bitxor_overflow_panics() {
+        let a = Set::from([1, 2]);
+        let b = Set::from([3]);
+        let _ = &a ^ &b;
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+
+impl<T, const N: usize> Set<T, N> {
+    /// Moves all the values into a set with another capacity `M`, which must
+    /// not be less than `N`, so the values always fit.
+    ///
+    /// The values are moved as they are, without any comparison, and their
+    /// order is kept. It does not compile if `M < N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let s = Set::from([1, 2]);
+    /// let mut s: Set<_, 8> = s.into_capacity();
+    /// s.insert(3);
+    /// assert_eq!(s.len(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_capacity<const M: usize>(self) -> Set<T, M> {
+        Set {
+            map: self.map.into_capacity(),
+        }
+    }
+
+    /// Attempts to move all the values into a set with another capacity `M`,
+    /// which may be less than `N`, as long as the values fit.
+    ///
+    /// # Errors
+    /// If there are more than `M` values, the set is returned back in [`Err`]
+    /// untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let s: Set<_, 8> = Set::from_iter([1, 2]);
+    /// let s: Set<_, 2> = s.try_into_capacity().unwrap();
+    /// assert!(s.try_into_capacity::<1>().is_err());
+    /// ```
+    #[inline]
+    pub fn try_into_capacity<const M: usize>(self) -> Result<Set<T, M>, Self> {
+        match self.map.try_into_capacity() {
+            Ok(map) => Ok(Set { map }),
+            Err(map) => Err(Self { map }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn changes_capacity() {
+        let s: Set<char, 3> = Set::from(['c', 'a', 'b']);
+        let s: Set<char, 6> = s.into_capacity();
+        assert_eq!(s.capacity(), 6);
+        assert_eq!(s.iter().copied().collect::<String>(), "cab");
+        let s = s.try_into_capacity::<2>().unwrap_err();
+        let s: Set<char, 3> = s.try_into_capacity().unwrap();
+        assert_eq!(s, Set::from(['a', 'b', 'c']));
+    }
+}
@@ -62,6 +62,13 @@ impl<K> Iterator for Drain<'_, K> {
     }
 }
 
+impl<K> DoubleEndedIterator for Drain<'_, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(k, ())| k)
+    }
+}
+
 impl<K> ExactSizeIterator for Drain<'_, K> {
     #[inline]
     fn len(&self) -> usize {
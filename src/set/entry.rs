@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use crate::map;
+use crate::Equivalent;
+
+impl<T: PartialEq, const N: usize> Set<T, N> {
+    /// Gets the given value's corresponding entry in the set for in-place
+    /// manipulation.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// use micromap::set::Entry;
+    /// let mut set: Set<&str, 3> = Set::new();
+    /// for word in ["a", "b", "a"] {
+    ///     match set.entry(word) {
+    ///         Entry::Occupied(o) => println!("{} again", o.get()),
+    ///         Entry::Vacant(v) => println!("{} for the first time", v.insert()),
+    ///     }
+    /// }
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[inline]
+    pub fn entry(&mut self, value: T) -> Entry<'_, T, N> {
+        match self.map.entry(value) {
+            map::Entry::Occupied(inner) => Entry::Occupied(OccupiedEntry { inner }),
+            map::Entry::Vacant(inner) => Entry::Vacant(VacantEntry { inner }),
+        }
+    }
+
+    /// Inserts the given value into the set if it is not present, then
+    /// returns a reference to the value in the set.
+    ///
+    /// # Panics
+    /// If the value is absent and the set is full already.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut set = Set::<_, 3>::from_iter([1, 2]);
+    /// assert_eq!(set.get_or_insert(2), &2);
+    /// assert_eq!(set.get_or_insert(3), &3);
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    #[inline]
+    pub fn get_or_insert(&mut self, value: T) -> &T {
+        self.entry(value).or_insert()
+    }
+
+    /// Inserts a value computed from `f` into the set if the given `value` is
+    /// not present, then returns a reference to the value in the set. The
+    /// value is looked up only once, and `f` is called only if it is absent.
+    ///
+    /// # Panics
+    /// If the value is absent and the set is full already.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut set: Set<String, 3> = Set::new();
+    /// let s = set.get_or_insert_with("hello", |s| s.to_owned());
+    /// assert_eq!(s, "hello");
+    /// set.get_or_insert_with("hello", |_| unreachable!());
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[inline]
+    pub fn get_or_insert_with<Q, F>(&mut self, value: &Q, f: F) -> &T
+    where
+        Q: Equivalent<T> + ?Sized,
+        F: FnOnce(&Q) -> T,
+    {
+        let i = match self.map.position_of(value) {
+            Some(i) => i,
+            None => self.map.push_back(f(value), ()),
+        };
+        unsafe { &self.map.item_ref(i).0 }
+    }
+}
+
+/// A view into a single entry in a set, which may either be vacant or occupied.
+///
+/// This `enum` is constructed from the [`entry`][Set::entry] method on [`Set`].
+pub enum Entry<'a, T, const N: usize> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, T, N>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, T, N>),
+}
+
+/// A view into an occupied entry in a `Set`.
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, T, const N: usize> {
+    inner: map::OccupiedEntry<'a, T, (), N>,
+}
+
+/// A view into a vacant entry in a `Set`.
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, T, const N: usize> {
+    inner: map::VacantEntry<'a, T, (), N>,
+}
+
+impl<T, const N: usize> Entry<'_, T, N> {
+    /// Returns a reference to this entry's value: the one in the set if
+    /// occupied, or the one to be inserted if vacant.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> &T {
+        match self {
+            Entry::Occupied(o) => o.get(),
+            Entry::Vacant(v) => v.get(),
+        }
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> Entry<'a, T, N> {
+    /// Ensures the value is in the set by inserting it if vacant, and returns
+    /// a reference to the value in the set.
+    ///
+    /// # Panics
+    /// If the entry is vacant and the set is full already.
+    #[inline]
+    pub fn or_insert(self) -> &'a T {
+        match self {
+            Entry::Occupied(o) => o.into_ref(),
+            Entry::Vacant(v) => v.insert(),
+        }
+    }
+
+    /// Ensures the value is in the set by inserting it if vacant, and returns
+    /// a reference to the value in the set.
+    ///
+    /// # Errors
+    /// If the entry is vacant and the set is full already, the value is
+    /// returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut set = Set::from([1]);
+    /// assert_eq!(set.entry(1).or_try_insert(), Ok(&1));
+    /// assert_eq!(set.entry(2).or_try_insert(), Err(2));
+    /// ```
+    #[inline]
+    pub fn or_try_insert(self) -> Result<&'a T, T> {
+        match self {
+            Entry::Occupied(o) => Ok(o.into_ref()),
+            Entry::Vacant(v) => v.try_insert(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> OccupiedEntry<'a, T, N> {
+    /// Gets a reference to the value in the set.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> &T {
+        self.inner.key()
+    }
+
+    /// Converts the entry into a reference to the value in the set, with a
+    /// lifetime bound to the set itself.
+    #[inline]
+    #[must_use]
+    pub fn into_ref(self) -> &'a T {
+        self.inner.into_key_ref()
+    }
+
+    /// Takes the value out of the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// use micromap::set::Entry;
+    /// let mut set = Set::from(["a", "b"]);
+    /// if let Entry::Occupied(o) = set.entry("a") {
+    ///     assert_eq!(o.remove(), "a");
+    /// }
+    /// assert!(!set.contains("a"));
+    /// ```
+    #[inline]
+    pub fn remove(self) -> T {
+        self.inner.remove_entry().0
+    }
+}
+
+impl<T, const N: usize> VacantEntry<'_, T, N> {
+    /// Gets a reference to the value that would be inserted.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> &T {
+        self.inner.key()
+    }
+
+    /// Takes ownership of the value.
+    #[inline]
+    #[must_use]
+    pub fn into_value(self) -> T {
+        self.inner.into_key()
+    }
+}
+
+impl<'a, T: PartialEq, const N: usize> VacantEntry<'a, T, N> {
+    /// Inserts the value into the set, and returns a reference to it.
+    ///
+    /// # Panics
+    /// If the set is full already. If you want to avoid this, use
+    /// [`try_insert()`][Self::try_insert] instead.
+    #[inline]
+    pub fn insert(self) -> &'a T {
+        self.inner.insert_entry(()).into_key_ref()
+    }
+
+    /// Attempts to insert the value into the set, and returns a reference to
+    /// it.
+    ///
+    /// # Errors
+    /// If the set is full already, the value is returned back in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// use micromap::set::Entry;
+    /// let mut set: Set<u8, 1> = Set::new();
+    /// if let Entry::Vacant(v) = set.entry(1) {
+    ///     assert_eq!(v.try_insert(), Ok(&1));
+    /// }
+    /// if let Entry::Vacant(v) = set.entry(2) {
+    ///     assert_eq!(v.try_insert(), Err(2));
+    /// }
+    /// ```
+    #[inline]
+    pub fn try_insert(self) -> Result<&'a T, T> {
+        if self.inner.is_full() {
+            return Err(self.into_value());
+        }
+        Ok(self.insert())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entry, Set};
+
+    #[test]
+    fn occupied_and_vacant() {
+        let mut set: Set<char, 2> = Set::from_iter(['a']);
+        let e = set.entry('a');
+        assert_eq!(e.get(), &'a');
+        assert!(matches!(e, Entry::Occupied(_)));
+        let e = set.entry('b');
+        assert_eq!(e.get(), &'b');
+        let Entry::Vacant(v) = e else {
+            unreachable!("'b' is not in the set");
+        };
+        assert_eq!(v.insert(), &'b');
+        assert_eq!(set.entry('c').or_try_insert(), Err('c'));
+        let Entry::Occupied(o) = set.entry('a') else {
+            unreachable!("'a' is in the set");
+        };
+        assert_eq!(o.remove(), 'a');
+        assert_eq!(set.entry('c').or_insert(), &'c');
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn interns_strings_once() {
+        let mut set: Set<String, 4> = Set::new();
+        let mut calls = 0;
+        for w in ["x", "y", "x", "x", "y"] {
+            set.get_or_insert_with(w, |s| {
+                calls += 1;
+                s.to_owned()
+            });
+        }
+        assert_eq!(calls, 2);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    fn get_or_insert_with_into_full_set_panics() {
+        let mut set = Set::from([1]);
+        set.get_or_insert_with(&2, |x| *x);
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use crate::map::Map;
+use core::iter::FusedIterator;
+
+impl<T, const N: usize> Set<T, N> {
+    /// Creates an iterator which uses a closure to determine if a value should
+    /// be removed. If the closure returns `true`, the value is removed from
+    /// the set and yielded. If the closure returns `false`, or panics, the
+    /// value remains in the set and will not be yielded.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the
+    /// rest values are kept in the set, whether they match or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut set: Set<u32, 8> = (0..8).collect();
+    /// let mut evens: Vec<_> = set.extract_if(|x| x % 2 == 0).collect();
+    /// evens.sort_unstable();
+    /// assert_eq!(evens, [0, 2, 4, 6]);
+    /// assert_eq!(set, Set::from([1, 3, 5, 7]));
+    /// ```
+    #[inline]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F, N>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        ExtractIf {
+            map: &mut self.map,
+            pred,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator which removes and yields the values of a `Set` matching a
+/// predicate.
+///
+/// This `struct` is created by the [`extract_if`][Set::extract_if] method on
+/// [`Set`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExtractIf<'a, T, F, const N: usize> {
+    map: &'a mut Map<T, (), N>,
+    pred: F,
+    /// The index of the next value to be checked.
+    index: usize,
+}
+
+impl<T, F, const N: usize> Iterator for ExtractIf<'_, T, F, N>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        while self.index < self.map.len() {
+            let (k, ()) = unsafe { self.map.item_ref(self.index) };
+            if (self.pred)(k) {
+                // the last value is swapped here, so check the same index again
+                return Some(unsafe { self.map.remove_index_read(self.index) }.0);
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len() - self.index))
+    }
+}
+
+impl<T, F, const N: usize> FusedIterator for ExtractIf<'_, T, F, N> where F: FnMut(&T) -> bool {}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn extracts_matching_values() {
+        let mut set: Set<u32, 10> = (0..10).collect();
+        let mut out: Vec<_> = set.extract_if(|x| x % 3 == 0).collect();
+        out.sort_unstable();
+        assert_eq!(out, [0, 3, 6, 9]);
+        assert_eq!(set.len(), 6);
+        assert!(set.iter().all(|x| x % 3 != 0));
+    }
+
+    #[test]
+    fn keeps_the_rest_when_dropped_early() {
+        let mut set: Set<u32, 4> = (0..4).collect();
+        let mut it = set.extract_if(|_| true);
+        assert_eq!(it.size_hint(), (0, Some(4)));
+        assert!(it.next().is_some());
+        drop(it);
+        assert_eq!(set.len(), 3);
+    }
+}
@@ -81,6 +81,20 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
     }
 }
 
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
 impl<'a, T, const N: usize> IntoIterator for &'a Set<T, N> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
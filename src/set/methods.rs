@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: MIT
 
 use super::Set;
-use core::borrow::Borrow;
+use crate::Equivalent;
 
 impl<T, const N: usize> Set<T, N> {
     /// Returns the number of elements the set can hold.
@@ -90,9 +90,9 @@ impl<T, const N: usize> Set<T, N> {
 impl<T: PartialEq, const N: usize> Set<T, N> {
     /// Returns `true` if the set contains a value.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`PartialEq`] on the borrowed form *must* match those for the value
-    /// type.
+    /// The value may be any borrowed form of the set's value type, or any
+    /// other type implementing [`Equivalent`] for it, but the equivalence
+    /// *must* match [`PartialEq`] on the value type.
     ///
     /// # Examples
     /// ```
@@ -105,8 +105,7 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     #[must_use]
     pub fn contains<Q>(&self, k: &Q) -> bool
     where
-        T: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
         self.map.contains_key(k)
     }
@@ -114,9 +113,9 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     /// Removes a value from the set. Returns whether the value was present
     /// in the set.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`PartialEq`] on the borrowed form *must* match those for the value
-    /// type.
+    /// The value may be any borrowed form of the set's value type, or any
+    /// other type implementing [`Equivalent`] for it, but the equivalence
+    /// *must* match [`PartialEq`] on the value type.
     ///
     /// # Examples
     /// ```
@@ -129,8 +128,7 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     #[inline]
     pub fn remove<Q>(&mut self, k: &Q) -> bool
     where
-        T: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
         self.map.remove(k).is_some()
     }
@@ -265,9 +263,9 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     /// Returns a reference to the value in the set, if any, that is equal
     /// to the given value.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`PartialEq`] on the borrowed form *must* match those for the value
-    /// type.
+    /// The value may be any borrowed form of the set's value type, or any
+    /// other type implementing [`Equivalent`] for it, but the equivalence
+    /// *must* match [`PartialEq`] on the value type.
     ///
     /// # Examples
     /// ```
@@ -280,8 +278,7 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     #[must_use]
     pub fn get<Q>(&self, k: &Q) -> Option<&T>
     where
-        T: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
         self.map.get_key_value(k).map(|p| p.0)
     }
@@ -289,9 +286,9 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     /// Removes and returns the value in the set, if any, that is equal to the
     /// given one.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// [`PartialEq`] on the borrowed form *must* match those for the value
-    /// type.
+    /// The value may be any borrowed form of the set's value type, or any
+    /// other type implementing [`Equivalent`] for it, but the equivalence
+    /// *must* match [`PartialEq`] on the value type.
     ///
     /// # Examples
     /// ```
@@ -304,8 +301,7 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     #[must_use = "if no need the return value, use `remove()` instead."]
     pub fn take<Q>(&mut self, k: &Q) -> Option<T>
     where
-        T: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
         self.map.remove_entry(k).map(|p| p.0)
     }
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use crate::Map;
+use core::mem::{size_of, ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+impl<T, const N: usize> Set<T, N> {
+    /// Returns the values as a slice, in the order of [`iter()`][Set::iter].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let s = Set::from([1, 2, 3]);
+    /// assert_eq!(s.as_slice(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        const { assert!(size_of::<(T, ())>() == size_of::<T>()) };
+        let pairs = self.map.as_slice();
+        // SAFETY: `(T, ())` has the same size as `T`, so `T` is at its
+        // beginning and the slices have the same layout.
+        unsafe { core::slice::from_raw_parts(pairs.as_ptr().cast(), pairs.len()) }
+    }
+
+    /// Consumes the set and returns its internal array together with the
+    /// number of values in it. The first `len` items of the array are
+    /// initialized, the others are not.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let s: Set<_, 4> = Set::from_iter(["a", "b"]);
+    /// let (values, len) = s.into_array();
+    /// assert_eq!(len, 2);
+    /// assert_eq!(unsafe { values[0].assume_init_read() }, "a");
+    /// ```
+    #[inline]
+    pub fn into_array(self) -> ([MaybeUninit<T>; N], usize) {
+        const { assert!(size_of::<(T, ())>() == size_of::<T>()) };
+        let (pairs, len) = self.map.into_array();
+        let pairs = ManuallyDrop::new(pairs);
+        // SAFETY: the arrays have the same layout, see `as_slice()`.
+        (unsafe { ptr::read(pairs.as_ptr().cast()) }, len)
+    }
+
+    /// Makes a set from an array, whose first `len` items are the values,
+    /// without any checks. This is the reverse of
+    /// [`into_array()`][Set::into_array].
+    ///
+    /// # Safety
+    /// The first `len` items of `values` must be initialized and unique, and
+    /// `len` must not be more than `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let (values, len) = Set::<_, 3>::from_iter([1, 2]).into_array();
+    /// let s = unsafe { Set::from_array_unchecked(values, len) };
+    /// assert!(s.contains(&2));
+    /// ```
+    #[inline]
+    pub unsafe fn from_array_unchecked(values: [MaybeUninit<T>; N], len: usize) -> Self {
+        const { assert!(size_of::<(T, ())>() == size_of::<T>()) };
+        let values = ManuallyDrop::new(values);
+        // SAFETY: the arrays have the same layout, see `as_slice()`.
+        let pairs = ptr::read(values.as_ptr().cast());
+        Self {
+            map: Map::from_array_unchecked(pairs, len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn views_and_round_trips() {
+        let mut s: Set<String, 4> = Set::from_iter(["x".to_string(), "y".to_string()]);
+        s.insert("z".to_string());
+        s.remove("x");
+        assert_eq!(s.as_slice(), ["z", "y"]);
+        let (values, len) = s.into_array();
+        let s = unsafe { Set::from_array_unchecked(values, len) };
+        assert_eq!(s.len(), 2);
+        assert!(s.contains("y"));
+    }
+}
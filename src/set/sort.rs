@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+use core::cmp::Ordering;
+
+impl<T, const N: usize> Set<T, N> {
+    /// Sorts the values in place.
+    ///
+    /// The sorted order is seen by [`iter()`][Set::iter] and the others,
+    /// until a removal swaps the last value into the gap.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut s = Set::from([3, 1, 2]);
+    /// s.sort();
+    /// assert_eq!(s.to_string(), "{1, 2, 3}");
+    /// ```
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys();
+    }
+
+    /// Sorts the values in place with a comparator function.
+    ///
+    /// This sort is stable, see [`Map::sort_by()`][crate::Map::sort_by].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut s = Set::from(["bb", "a", "cc"]);
+    /// s.sort_by(|a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(s.iter().copied().collect::<Vec<_>>(), ["a", "bb", "cc"]);
+    /// ```
+    #[inline]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_by(|(a, ()), (b, ())| compare(a, b));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn sorts_values() {
+        let mut s: Set<char, 5> = Set::from_iter(['d', 'b', 'e', 'a']);
+        s.sort();
+        assert_eq!(s.iter().collect::<String>(), "abde");
+        s.sort_by(|a, b| b.cmp(a));
+        assert_eq!(s.iter().collect::<String>(), "edba");
+    }
+}
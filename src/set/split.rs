@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::Set;
+
+impl<T, const N: usize> Set<T, N> {
+    /// Splits the set into two by the predicate: the values for which it
+    /// returns `true` go to the first set, the others go to the second one.
+    ///
+    /// The values are moved without any comparison, and their order is kept
+    /// in both sets.
+    ///
+    /// # Panics
+    /// If one of the two sets can not hold all its values.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let s = Set::from([1, 2, 3, 4]);
+    /// let (even, odd): (Set<_, 4>, Set<_, 4>) = s.partition(|x| x % 2 == 0);
+    /// assert_eq!(even, Set::from([2, 4]));
+    /// assert_eq!(odd, Set::from([1, 3]));
+    /// ```
+    #[inline]
+    pub fn partition<const A: usize, const B: usize, F>(self, mut pred: F) -> (Set<T, A>, Set<T, B>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (left, right) = self.map.partition(|x, ()| pred(x));
+        (Set { map: left }, Set { map: right })
+    }
+
+    /// Splits the set into two at the given position of the internal array.
+    /// Returns a set with the values at `[at, len)`, and `self` keeps the
+    /// values at `[0, at)`.
+    ///
+    /// # Panics
+    /// If `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let mut a = Set::from([1, 2, 3]);
+    /// let b = a.split_off(2);
+    /// assert_eq!(a, Set::from([1, 2]));
+    /// assert_eq!(b, Set::from([3]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self {
+            map: self.map.split_off(at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Set;
+
+    #[test]
+    fn splits_in_order() {
+        let mut s: Set<u8, 5> = (0..5).collect();
+        let rest = s.split_off(3);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), [3, 4]);
+        let (small, big): (Set<u8, 2>, Set<u8, 1>) = s.partition(|x| *x < 2);
+        assert_eq!(small.iter().copied().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(big.iter().copied().collect::<Vec<_>>(), [2]);
+    }
+}
@@ -32,6 +32,41 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     }
 }
 
+impl<T: PartialEq + Clone, const N: usize> Set<T, N> {
+    /// Clones the symmetric difference of `self` and `other` into a new set,
+    /// whose capacity `R` is chosen by the caller.
+    ///
+    /// # Errors
+    /// If the symmetric difference has more than `R` values, the first value
+    /// which does not fit is returned in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let a = Set::from([1, 2, 3]);
+    /// let b = Set::from([2, 3, 4]);
+    /// let sym_diff: Set<_, 2> = a.symmetric_difference_into(&b).unwrap();
+    /// assert_eq!(sym_diff, Set::from([1, 4]));
+    /// let too_small: Result<Set<_, 1>, _> = a.symmetric_difference_into(&b);
+    /// assert_eq!(too_small, Err(4));
+    /// ```
+    #[inline]
+    pub fn symmetric_difference_into<const R: usize, const M: usize>(
+        &self,
+        other: &Set<T, M>,
+    ) -> Result<Set<T, R>, T> {
+        let mut set = Set::new();
+        for x in self.symmetric_difference(other) {
+            if set.len() == R {
+                return Err(x.clone());
+            }
+            // the values of a symmetric difference are distinct already
+            set.map.push_back(x.clone(), ());
+        }
+        Ok(set)
+    }
+}
+
 /// A lazy iterator producing elements in the symmetric difference of Linear `Set`s.
 ///
 /// This `struct` is created by the [`symmetric_difference`] method on [`Set`].
@@ -103,6 +138,18 @@ impl<T: PartialEq, const N: usize, const M: usize> core::iter::FusedIterator
 mod tests {
     use super::Set;
 
+    #[test]
+    fn symmetric_difference_into_chosen_capacity() {
+        let set_a = Set::from([0, 1, 2, 3]);
+        let set_b = Set::from([2, 3, 4, 5]);
+        let sym_diff: Set<_, 4> = set_a.symmetric_difference_into(&set_b).unwrap();
+        assert_eq!(sym_diff, Set::from([0, 1, 4, 5]));
+        let too_small: Result<Set<_, 3>, _> = set_a.symmetric_difference_into(&set_b);
+        assert_eq!(too_small, Err(5));
+        let same: Set<_, 0> = set_a.symmetric_difference_into(&set_a).unwrap();
+        assert!(same.is_empty());
+    }
+
     #[test]
     fn symmetric_difference_simple() {
         let set_a = Set::from([0, 1, 2, 3, 5, 7, 9]);
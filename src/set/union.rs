@@ -30,6 +30,41 @@ impl<T: PartialEq, const N: usize> Set<T, N> {
     }
 }
 
+impl<T: PartialEq + Clone, const N: usize> Set<T, N> {
+    /// Clones the union of `self` and `other` into a new set, whose capacity
+    /// `R` is chosen by the caller.
+    ///
+    /// # Errors
+    /// If the union has more than `R` values, the first value which does not
+    /// fit is returned in [`Err`].
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::Set;
+    /// let a = Set::from([1, 2, 3]);
+    /// let b = Set::from([2, 3, 4]);
+    /// let union: Set<_, 4> = a.union_into(&b).unwrap();
+    /// assert_eq!(union, Set::from([1, 2, 3, 4]));
+    /// let too_small: Result<Set<_, 3>, _> = a.union_into(&b);
+    /// assert!(too_small.is_err());
+    /// ```
+    #[inline]
+    pub fn union_into<const R: usize, const M: usize>(
+        &self,
+        other: &Set<T, M>,
+    ) -> Result<Set<T, R>, T> {
+        let mut set = Set::new();
+        for x in self.union(other) {
+            if set.len() == R {
+                return Err(x.clone());
+            }
+            // the values of a union are distinct already
+            set.map.push_back(x.clone(), ());
+        }
+        Ok(set)
+    }
+}
+
 /// A lazy iterator producing elements in the union of Linear `Set`s.
 ///
 /// This `struct` is created by the [`union`] method on [`Set`].
@@ -112,6 +147,17 @@ mod tests {
         assert_eq!(set_result, Set::from([0, 1, 2, 3, 5, 6, 7, 8, 9, 10]));
     }
 
+    #[test]
+    fn union_into_chosen_capacity() {
+        let set_a = Set::from([0, 1, 2, 3]);
+        let set_b = Set::from([2, 3, 4, 5]);
+        let union: Set<_, 6> = set_a.union_into(&set_b).unwrap();
+        assert_eq!(union, Set::from([0, 1, 2, 3, 4, 5]));
+        assert!(set_a.union_into::<5, 4>(&set_b).is_err());
+        let empty: Set<i32, 0> = Set::new();
+        assert_eq!(empty.union_into::<0, 0>(&empty), Ok(Set::new()));
+    }
+
     #[test]
     fn union_with_empty_set() {
         let set_a = Set::from([1, 2, 3]);
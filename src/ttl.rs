@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+//! A small Map whose entries expire, driven by a caller-supplied clock.
+
+mod methods;
+mod purge;
+
+// re-export
+pub use purge::PurgeExpired;
+
+use crate::map::Map;
+use core::fmt;
+
+/// A fixed-capacity map whose entries expire at a given deadline.
+///
+/// Each pair is stored together with its deadline in the array of the inner
+/// [`Map`]. The deadline is of any [`Ord`] type `T` chosen by the caller (a tick
+/// counter, a `u64` of milliseconds, an `Instant`...), and the current time is
+/// passed into every time-aware method, so no `std::time` is needed at all.
+///
+/// An entry is considered expired when its deadline is less than or equal to
+/// the current time `now`. Expired entries are not returned by lookups, but
+/// they keep occupying their slots until [`purge_expired()`] is called, or until
+/// they are evicted by [`insert_evicting()`].
+///
+/// ```
+/// use micromap::TtlMap;
+/// let mut m: TtlMap<&str, u32, u64, 4> = TtlMap::new();
+/// m.insert("a", 1, 10); // expires at 10
+/// m.insert("b", 2, 20); // expires at 20
+/// assert_eq!(m.get(&"a", 5), Some(&1));
+/// assert_eq!(m.get(&"a", 10), None);
+/// assert_eq!(m.next_deadline(), Some(&10));
+/// assert_eq!(m.purge_expired(15).collect::<Vec<_>>(), [("a", 1)]);
+/// assert_eq!(m.len(), 1);
+/// ```
+///
+/// [`purge_expired()`]: TtlMap::purge_expired
+/// [`insert_evicting()`]: TtlMap::insert_evicting
+pub struct TtlMap<K, V, T, const N: usize> {
+    map: Map<K, (V, T), N>,
+}
+
+impl<K, V, T, const N: usize> Default for TtlMap<K, V, T, N> {
+    /// Creates an empty [`TtlMap`] like [`new()`][`TtlMap::new`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, T: Clone, const N: usize> Clone for TtlMap<K, V, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K, V, T, const N: usize> fmt::Debug for TtlMap<K, V, T, N>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TtlMap;
+
+    #[test]
+    fn various() {
+        let mut m: TtlMap<char, u8, u32, 3> = TtlMap::default();
+        assert!(m.is_empty());
+        assert_eq!(m.capacity(), 3);
+        assert_eq!(m.next_deadline(), None);
+        assert_eq!(m.insert('a', 1, 30), None);
+        assert_eq!(m.insert('b', 2, 10), None);
+        assert_eq!(m.insert('a', 3, 20), Some(1));
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.next_deadline(), Some(&10));
+        assert_eq!(format!("{m:?}"), "{'a': (3, 20), 'b': (2, 10)}");
+        let mut cloned = m.clone();
+        assert_eq!(cloned.purge_expired(15).count(), 1);
+        assert_eq!(cloned.len(), 1);
+        assert_eq!(m.len(), 2);
+        m.clear();
+        assert!(m.is_empty());
+    }
+}
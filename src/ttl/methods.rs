@@ -0,0 +1,334 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use super::purge::PurgeExpired;
+use super::TtlMap;
+use crate::map::Map;
+use crate::Equivalent;
+
+impl<K, V, T, const N: usize> TtlMap<K, V, T, N> {
+    /// Creates an empty [`TtlMap`] with capacity `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let m: TtlMap<&str, i32, u64, 8> = TtlMap::new();
+    /// assert_eq!(m.capacity(), 8);
+    /// assert_eq!(m.len(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { map: Map::new() }
+    }
+
+    /// Returns the number of pairs the map can hold, which always equal to `N`.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of pairs in the map, including the expired ones
+    /// which are not purged yet.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no pair (neither alive nor expired).
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Clears the map, removing all pairs (drop them).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K, V, T: Ord, const N: usize> TtlMap<K, V, T, N> {
+    /// Returns the soonest deadline among all the pairs in the map (including
+    /// the expired ones which are not purged yet), or [`None`] if it is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+    /// assert_eq!(m.next_deadline(), None);
+    /// m.insert(1, "a", 30);
+    /// m.insert(2, "b", 10);
+    /// assert_eq!(m.next_deadline(), Some(&10));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<&T> {
+        self.map.values().map(|(_, t)| t).min()
+    }
+
+    /// Removes all the expired pairs (whose deadline is not after `now`) from
+    /// the map, returning them as an iterator.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, it
+    /// still removes (and drops) the remaining expired pairs. The pairs that
+    /// are still alive are kept in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 4> = TtlMap::new();
+    /// m.insert(1, "a", 10);
+    /// m.insert(2, "b", 20);
+    /// m.insert(3, "c", 30);
+    /// let mut expired: Vec<_> = m.purge_expired(20).collect();
+    /// expired.sort_unstable();
+    /// assert_eq!(expired, [(1, "a"), (2, "b")]);
+    /// assert_eq!(m.len(), 1);
+    /// ```
+    #[inline]
+    pub fn purge_expired(&mut self, now: T) -> PurgeExpired<'_, K, V, T, N> {
+        PurgeExpired {
+            map: &mut self.map,
+            now,
+            index: 0,
+        }
+    }
+
+    /// Returns the index of the pair with the soonest deadline.
+    #[inline]
+    fn soonest_index(&self) -> Option<usize> {
+        self.map
+            .values()
+            .enumerate()
+            .min_by(|(_, (_, a)), (_, (_, b))| a.cmp(b))
+            .map(|(i, _)| i)
+    }
+}
+
+impl<K: PartialEq, V, T: Ord, const N: usize> TtlMap<K, V, T, N> {
+    /// Returns a reference to the value corresponding to the key, if the pair
+    /// is not expired at `now`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+    /// m.insert("a", 1, 10);
+    /// assert_eq!(m.get("a", 9), Some(&1));
+    /// assert_eq!(m.get("a", 10), None);
+    /// assert_eq!(m.get("b", 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)] // timestamps are usually `Copy`
+    pub fn get<Q>(&self, k: &Q, now: T) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let (v, deadline) = self.map.get(k)?;
+        (*deadline > now).then_some(v)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key, if
+    /// the pair is not expired at `now`.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+    /// m.insert("a", 1, 10);
+    /// *m.get_mut("a", 5).unwrap() += 1;
+    /// assert_eq!(m.get("a", 5), Some(&2));
+    /// assert_eq!(m.get_mut("a", 10), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)] // timestamps are usually `Copy`
+    pub fn get_mut<Q>(&mut self, k: &Q, now: T) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let (v, deadline) = self.map.get_mut(k)?;
+        (*deadline > now).then_some(v)
+    }
+
+    /// Returns a reference to the deadline of the pair with the given key,
+    /// whether it is expired or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+    /// m.insert("a", 1, 10);
+    /// assert_eq!(m.deadline("a"), Some(&10));
+    /// assert_eq!(m.deadline("b"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn deadline<Q>(&self, k: &Q) -> Option<&T>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get(k).map(|(_, t)| t)
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map (whether it is expired or not).
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+    /// m.insert("a", 1, 10);
+    /// assert_eq!(m.remove("a"), Some(1));
+    /// assert_eq!(m.remove("a"), None);
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.remove(k).map(|(v, _)| v)
+    }
+
+    /// Inserts a pair which expires at `deadline` into the map.
+    ///
+    /// If the key exists already (even if it is expired), both the value and
+    /// the deadline are updated, and the old value is returned.
+    ///
+    /// # Panics
+    /// It may panic if there are too many pairs in the map already. If you want
+    /// to make room for it, use [`insert_evicting()`][Self::insert_evicting]
+    /// instead, or call [`purge_expired()`][Self::purge_expired] before.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+    /// assert_eq!(m.insert("a", 1, 10), None);
+    /// assert_eq!(m.insert("a", 2, 20), Some(1));
+    /// assert_eq!(m.deadline("a"), Some(&20));
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V, deadline: T) -> Option<V> {
+        self.map.insert(k, (v, deadline)).map(|(v, _)| v)
+    }
+
+    /// Inserts a pair which expires at `deadline` into the map, evicting the
+    /// pair with the soonest deadline when there is no room for it.
+    ///
+    /// Returns the evicted pair, if any:
+    /// - If the key exists already, both the value and the deadline are
+    ///   updated, the old value is dropped, and [`None`] is returned, since
+    ///   nothing is evicted;
+    /// - If the key does not exist and the map is full, the pair with the
+    ///   soonest deadline (expired or not) is evicted and returned;
+    /// - Otherwise, [`None`] is returned.
+    ///
+    /// For a map with zero capacity, the given pair is evicted and returned
+    /// at once. Use [`insert()`][Self::insert] to get the old value back.
+    ///
+    /// # Examples
+    /// ```
+    /// use micromap::TtlMap;
+    /// let mut m: TtlMap<_, _, u64, 2> = TtlMap::new();
+    /// assert_eq!(m.insert_evicting("a", 1, 30), None);
+    /// assert_eq!(m.insert_evicting("b", 2, 10), None);
+    /// assert_eq!(m.insert_evicting("c", 3, 20), Some(("b", 2)));
+    /// assert_eq!(m.insert_evicting("a", 4, 40), None); // updated, not evicted
+    /// assert_eq!(m.len(), 2);
+    /// assert_eq!(m.get("a", 0), Some(&4));
+    /// ```
+    #[inline]
+    pub fn insert_evicting(&mut self, k: K, v: V, deadline: T) -> Option<(K, V)> {
+        if let Some(pair) = self.map.get_mut(&k) {
+            *pair = (v, deadline);
+            return None;
+        }
+        let evicted = if self.map.len() == N {
+            let Some(i) = self.soonest_index() else {
+                return Some((k, v));
+            };
+            let (k, (v, _)) = unsafe { self.map.remove_index_read(i) };
+            Some((k, v))
+        } else {
+            None
+        };
+        self.map.insert(k, (v, deadline));
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TtlMap;
+
+    #[test]
+    fn lookups_respect_deadline() {
+        let mut m: TtlMap<String, i32, u64, 4> = TtlMap::new();
+        m.insert("a".to_string(), 1, 10);
+        assert_eq!(m.get("a", 0), Some(&1));
+        assert_eq!(m.get("a", 9), Some(&1));
+        assert_eq!(m.get("a", 10), None);
+        assert_eq!(m.get("a", 11), None);
+        assert!(m.get_mut("a", 10).is_none());
+        assert_eq!(m.deadline("a"), Some(&10));
+        assert_eq!(m.len(), 1);
+    }
+
+    #[test]
+    fn insert_refreshes_expired_key() {
+        let mut m: TtlMap<i32, i32, u64, 1> = TtlMap::new();
+        m.insert(1, 10, 5);
+        assert_eq!(m.get(&1, 6), None);
+        assert_eq!(m.insert(1, 20, 50), Some(10));
+        assert_eq!(m.get(&1, 6), Some(&20));
+        assert_eq!(m.len(), 1);
+    }
+
+    #[test]
+    fn insert_evicting_drops_soonest() {
+        let mut m: TtlMap<i32, i32, u64, 3> = TtlMap::new();
+        assert_eq!(m.insert_evicting(1, 1, 30), None);
+        assert_eq!(m.insert_evicting(2, 2, 10), None);
+        assert_eq!(m.insert_evicting(3, 3, 20), None);
+        assert_eq!(m.insert_evicting(4, 4, 5), Some((2, 2)));
+        assert_eq!(m.next_deadline(), Some(&5));
+        assert_eq!(m.insert_evicting(5, 5, 50), Some((4, 4)));
+        assert_eq!(m.insert_evicting(3, 33, 60), None);
+        assert_eq!(m.get(&3, 0), Some(&33));
+        assert_eq!(m.next_deadline(), Some(&30));
+        assert_eq!(m.len(), 3);
+    }
+
+    #[test]
+    fn insert_evicting_into_zero_capacity() {
+        let mut m: TtlMap<i32, i32, u64, 0> = TtlMap::new();
+        assert_eq!(m.insert_evicting(1, 1, 1), Some((1, 1)));
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "No more key-value slot available in the map")]
+    #[cfg(debug_assertions)]
+    fn insert_into_full_map_panics() {
+        let mut m: TtlMap<i32, i32, u64, 1> = TtlMap::new();
+        m.insert(1, 1, 1);
+        m.insert(2, 2, 2);
+    }
+
+    #[test]
+    fn remove_any_pair() {
+        let mut m: TtlMap<i32, i32, u64, 2> = TtlMap::new();
+        m.insert(1, 1, 1);
+        m.insert(2, 2, 100);
+        assert_eq!(m.remove(&1), Some(1));
+        assert_eq!(m.remove(&1), None);
+        assert_eq!(m.next_deadline(), Some(&100));
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: Copyright (c) 2023-2025 Yegor Bugayenko
+// SPDX-FileCopyrightText: Copyright (c) 2025 owtotwo
+// SPDX-License-Identifier: MIT
+
+use crate::map::Map;
+use core::iter::FusedIterator;
+
+/// A draining iterator over the expired pairs of a [`TtlMap`].
+///
+/// This `struct` is created by the [`purge_expired`][TtlMap::purge_expired]
+/// method on [`TtlMap`]. See its documentation for more.
+///
+/// [`TtlMap`]: super::TtlMap
+/// [TtlMap::purge_expired]: super::TtlMap::purge_expired
+///
+/// # Example
+/// ```
+/// use micromap::TtlMap;
+/// let mut m: TtlMap<_, _, u64, 3> = TtlMap::new();
+/// m.insert(1, "a", 10);
+/// m.insert(2, "b", 20);
+/// let purge = m.purge_expired(100);
+/// drop(purge); // the expired pairs are removed even if not consumed
+/// assert!(m.is_empty());
+/// ```
+#[must_use = "iterators are lazy, but the expired pairs are removed when it is dropped"]
+pub struct PurgeExpired<'a, K, V, T: Ord, const N: usize> {
+    pub(super) map: &'a mut Map<K, (V, T), N>,
+    pub(super) now: T,
+    /// The index of the next pair to be checked.
+    pub(super) index: usize,
+}
+
+impl<K, V, T: Ord, const N: usize> Drop for PurgeExpired<'_, K, V, T, N> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+impl<K, V, T: Ord, const N: usize> Iterator for PurgeExpired<'_, K, V, T, N> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        while self.index < self.map.len() {
+            let (_, (_, deadline)) = unsafe { self.map.item_ref(self.index) };
+            if *deadline <= self.now {
+                // the last pair is swapped here, so check the same index again
+                let (k, (v, _)) = unsafe { self.map.remove_index_read(self.index) };
+                return Some((k, v));
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len() - self.index))
+    }
+}
+
+impl<K, V, T: Ord, const N: usize> FusedIterator for PurgeExpired<'_, K, V, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::TtlMap;
+
+    #[test]
+    fn purges_only_expired() {
+        let mut m: TtlMap<u64, u64, u64, 6> = TtlMap::new();
+        for i in 0..6 {
+            m.insert(i, i * 10, i % 3);
+        }
+        let mut purge = m.purge_expired(1);
+        assert_eq!(purge.size_hint(), (0, Some(6)));
+        let mut expired: Vec<_> = purge.by_ref().collect();
+        assert!(purge.next().is_none());
+        drop(purge);
+        expired.sort_unstable();
+        assert_eq!(expired, [(0, 0), (1, 10), (3, 30), (4, 40)]);
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get(&2, 1), Some(&20));
+        assert_eq!(m.get(&5, 1), Some(&50));
+    }
+
+    #[test]
+    fn drop_purges_the_rest() {
+        use std::rc::Rc;
+        let v = Rc::new(());
+        let mut m: TtlMap<u64, Rc<()>, u64, 4> = TtlMap::new();
+        for i in 0..4 {
+            m.insert(i, Rc::clone(&v), i);
+        }
+        let first = m.purge_expired(2).next();
+        assert!(first.is_some());
+        drop(first);
+        assert_eq!(m.len(), 1);
+        assert_eq!(Rc::strong_count(&v), 2);
+        assert_eq!(m.next_deadline(), Some(&3));
+    }
+}